@@ -0,0 +1,185 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::types::Finding;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+
+const BASELINE_VERSION: u32 = 1;
+
+/// Known findings recorded by `patina baseline create`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Baseline {
+    pub version: u32,
+    pub findings: Vec<BaselineEntry>,
+}
+
+/// A recorded finding. `line` and `message` are informational only; matching
+/// uses the rule, file and fingerprint so entries survive code moving around.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub rule_id: String,
+    pub file: String,
+    pub fingerprint: String,
+    pub line: usize,
+    pub message: String,
+}
+
+/// Result of filtering scan findings through a baseline.
+pub struct BaselineDiff {
+    pub new: Vec<Finding>,
+    pub matched: usize,
+    /// Baseline entries for scanned files that no longer match any finding.
+    pub fixed: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    pub fn from_findings(findings: &[Finding], sources: &HashMap<PathBuf, Vec<u8>>) -> Self {
+        let findings = findings
+            .iter()
+            .map(|f| BaselineEntry {
+                rule_id: f.rule_id.to_string(),
+                file: normalize_path(&f.file),
+                fingerprint: fingerprint(f, sources.get(&f.file).map_or(&[], Vec::as_slice)),
+                line: f.line,
+                message: f.message.clone(),
+            })
+            .collect();
+        Self { version: BASELINE_VERSION, findings }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read baseline {}: {e}", path.display()))?;
+        let baseline: Self = serde_json::from_str(&text)
+            .map_err(|e| format!("Invalid baseline {}: {e}", path.display()))?;
+        if baseline.version != BASELINE_VERSION {
+            return Err(format!(
+                "Unsupported baseline version {} in {} (expected {BASELINE_VERSION})",
+                baseline.version,
+                path.display()
+            ));
+        }
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json + "\n")
+            .map_err(|e| format!("Failed to write baseline {}: {e}", path.display()))
+    }
+
+    /// Splits `findings` into new ones and ones already recorded. Each entry
+    /// matches at most one finding, so a duplicated pattern still shows up.
+    pub fn diff(&self, findings: Vec<Finding>, sources: &HashMap<PathBuf, Vec<u8>>) -> BaselineDiff {
+        let mut remaining: HashMap<(String, String, String), Vec<&BaselineEntry>> = HashMap::new();
+        for entry in &self.findings {
+            let key = (entry.rule_id.clone(), entry.file.clone(), entry.fingerprint.clone());
+            remaining.entry(key).or_default().push(entry);
+        }
+
+        let mut new = Vec::new();
+        let mut matched = 0;
+        for finding in findings {
+            let key = (
+                finding.rule_id.to_string(),
+                normalize_path(&finding.file),
+                fingerprint(&finding, sources.get(&finding.file).map_or(&[], Vec::as_slice)),
+            );
+            let hit = remaining.get_mut(&key).and_then(Vec::pop);
+            match hit {
+                Some(_) => matched += 1,
+                None => new.push(finding),
+            }
+        }
+
+        let scanned: Vec<String> = sources.keys().map(|p| normalize_path(p)).collect();
+        let mut fixed: Vec<BaselineEntry> = remaining
+            .into_values()
+            .flatten()
+            .filter(|e| scanned.contains(&e.file))
+            .cloned()
+            .collect();
+        fixed.sort_by(|a, b| a.file.cmp(&b.file).then(a.line.cmp(&b.line)));
+
+        BaselineDiff { new, matched, fixed }
+    }
+}
+
+/// Hashes the finding's source text plus one line of context on either side,
+/// with whitespace collapsed, so the fingerprint survives line shifts and
+/// re-indentation but changes when the flagged code or its neighbours change.
+pub fn fingerprint(finding: &Finding, source: &[u8]) -> String {
+    let text = String::from_utf8_lossy(source);
+    let lines: Vec<&str> = text.lines().collect();
+    let span_text = text.get(finding.span.clone()).unwrap_or_default();
+
+    let first = finding.line.saturating_sub(1);
+    let last = first + span_text.matches('\n').count();
+    let before = first.checked_sub(1).and_then(|i| lines.get(i)).copied().unwrap_or_default();
+    let after = lines.get(last + 1).copied().unwrap_or_default();
+
+    let normalized: Vec<String> = [before, span_text, after]
+        .iter()
+        .map(|part| part.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect();
+    format!("{:016x}", fnv1a(normalized.join("\n").as_bytes()))
+}
+
+/// FNV-1a, used instead of `DefaultHasher` because baseline files must hash
+/// identically across Rust releases.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+/// Renders a path with `/` separators and without `./` segments so baselines
+/// are portable between platforms and invocation styles.
+pub fn normalize_path(path: &Path) -> String {
+    path.components()
+        .filter(|c| !matches!(c, Component::CurDir))
+        .map(|c| match c {
+            // Joined with `/`, an empty root segment yields a leading slash
+            Component::RootDir => "".into(),
+            _ => c.as_os_str().to_string_lossy(),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fingerprint_ignores_line_shifts_and_indentation() {
+        let original = "a();\n// Set the name\nsetName(n);\n";
+        let shifted = "\n\nb();\na();\n    // Set the name\n    setName(n);\n";
+        assert_eq!(
+            fingerprint(&Finding::test_at(original, "// Set the name"), original.as_bytes()),
+            fingerprint(&Finding::test_at(shifted, "// Set the name"), shifted.as_bytes()),
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_changes_with_surrounding_code() {
+        let original = "// Set the name\nsetName(n);\n";
+        let edited = "// Set the name\nsetTitle(n);\n";
+        assert_ne!(
+            fingerprint(&Finding::test_at(original, "// Set the name"), original.as_bytes()),
+            fingerprint(&Finding::test_at(edited, "// Set the name"), edited.as_bytes()),
+        );
+    }
+
+    #[test]
+    fn test_normalize_path() {
+        assert_eq!(normalize_path(Path::new("./src/app.js")), "src/app.js");
+        assert_eq!(normalize_path(Path::new("/repo/src/app.js")), "/repo/src/app.js");
+    }
+}
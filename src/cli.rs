@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
 use clap::{Parser, Subcommand, ValueEnum};
+use serde::Deserialize;
 use std::path::PathBuf;
 
 #[derive(Parser)]
@@ -19,13 +20,59 @@ pub enum Command {
         /// Path to scan (file or directory)
         path: PathBuf,
 
-        /// Output format
-        #[arg(long, default_value = "terminal")]
-        format: OutputFormat,
+        /// Output format [default: terminal, or `format` from .patina.toml]
+        #[arg(long)]
+        format: Option<OutputFormat>,
+
+        /// Minimum severity to report (error, warn, info) [default: info, or `severity` from .patina.toml]
+        #[arg(long)]
+        severity_threshold: Option<SeverityThreshold>,
+
+        /// Only report findings that are not recorded in this baseline file
+        #[arg(long, value_name = "FILE")]
+        baseline: Option<PathBuf>,
+
+        /// Only report findings on lines changed since the merge base with this git ref
+        #[arg(long, value_name = "BASE_REF", conflicts_with_all = ["staged", "diff_file"])]
+        diff: Option<String>,
+
+        /// Scan the staged content and only report findings on lines changed in the git index
+        #[arg(long, conflicts_with = "diff_file")]
+        staged: bool,
+
+        /// Only report findings on lines added by this unified diff (`-` for stdin)
+        #[arg(long, value_name = "FILE")]
+        diff_file: Option<PathBuf>,
+
+        /// Number of worker threads (0 = one per CPU)
+        #[arg(long, short = 'j', default_value_t = 0)]
+        jobs: usize,
+    },
+
+    /// Apply the machine-applicable fixes attached to findings
+    Fix {
+        /// Path to fix (file or directory)
+        path: PathBuf,
+
+        /// Print a unified diff instead of writing files
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Number of worker threads (0 = one per CPU)
+        #[arg(long, short = 'j', default_value_t = 0)]
+        jobs: usize,
+    },
 
-        /// Minimum severity to report (error, warn, info)
-        #[arg(long, default_value = "info")]
-        severity_threshold: SeverityThreshold,
+    /// Manage the baseline of known findings
+    Baseline {
+        #[command(subcommand)]
+        command: BaselineCommand,
+    },
+
+    /// Manage git hooks
+    Hook {
+        #[command(subcommand)]
+        command: HookCommand,
     },
 
     /// List all available rules
@@ -33,16 +80,50 @@ pub enum Command {
         /// Output format
         #[arg(long, default_value = "terminal")]
         format: OutputFormat,
+
+        /// Show the rationale, examples and options for one rule
+        #[arg(long, value_name = "RULE_ID")]
+        explain: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum BaselineCommand {
+    /// Record current findings so later scans only report new ones
+    Create {
+        /// Path to scan (file or directory)
+        path: PathBuf,
+
+        /// Where to write the baseline
+        #[arg(long, default_value = ".patina-baseline.json")]
+        output: PathBuf,
+
+        /// Number of worker threads (0 = one per CPU)
+        #[arg(long, short = 'j', default_value_t = 0)]
+        jobs: usize,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum HookCommand {
+    /// Install a pre-commit hook that scans staged changes
+    Install {
+        /// Replace a pre-commit hook that patina did not install
+        #[arg(long)]
+        force: bool,
     },
 }
 
-#[derive(Clone, ValueEnum)]
+#[derive(Debug, Clone, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum OutputFormat {
     Terminal,
     Json,
+    Sarif,
 }
 
-#[derive(Clone, Copy, ValueEnum)]
+#[derive(Debug, Clone, Copy, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum SeverityThreshold {
     Error,
     Warn,
@@ -0,0 +1,271 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::cli::{OutputFormat, SeverityThreshold};
+use crate::engine::RuleEngine;
+use crate::rules::plugin::PluginRule;
+use crate::rules::query::QueryRule;
+use crate::rules::{self, Rule};
+use crate::types::Severity;
+use ignore::overrides::{Override, OverrideBuilder};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+pub const CONFIG_FILE_NAME: &str = ".patina.toml";
+
+/// Project configuration loaded from `.patina.toml`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default)]
+    pub patina: GlobalConfig,
+    #[serde(default)]
+    pub rules: RulesConfig,
+
+    /// Directory containing the config file; `ignore` globs are relative to it.
+    #[serde(skip)]
+    pub root: PathBuf,
+
+    /// Rules from `[rules] custom` and `[rules] plugins`, loaded once when
+    /// the config is parsed.
+    #[serde(skip)]
+    loaded: LoadedRules,
+}
+
+#[derive(Default)]
+struct LoadedRules {
+    queries: Vec<QueryRule>,
+    plugins: Vec<PluginRule>,
+}
+
+impl std::fmt::Debug for LoadedRules {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ids: Vec<&str> = self.queries.iter().map(|r| r.id()).chain(self.plugins.iter().map(|r| r.id())).collect();
+        f.debug_tuple("LoadedRules").field(&ids).finish()
+    }
+}
+
+/// The `[patina]` table: defaults for CLI flags plus extra ignore globs.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GlobalConfig {
+    pub severity: Option<SeverityThreshold>,
+    pub format: Option<OutputFormat>,
+    #[serde(default)]
+    pub ignore: Vec<String>,
+}
+
+/// The `[rules]` table: disabled rules, severity overrides and per-rule options.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RulesConfig {
+    #[serde(default)]
+    pub disable: Vec<String>,
+    #[serde(default)]
+    pub severity: BTreeMap<String, Severity>,
+    #[serde(default)]
+    pub config: BTreeMap<String, toml::Value>,
+    /// Rule files, or directories of `*.toml` rule files, defining query rules.
+    #[serde(default)]
+    pub custom: Vec<String>,
+    /// WebAssembly plugins, or directories of `*.wasm` plugins, defining rules.
+    #[serde(default)]
+    pub plugins: Vec<String>,
+}
+
+impl Config {
+    /// Finds the nearest `.patina.toml` at or above `start` and loads it.
+    /// Returns the default config when no file is found.
+    pub fn discover(start: &Path) -> Result<Self, String> {
+        let start = std::path::absolute(start)
+            .map_err(|e| format!("Failed to resolve {}: {e}", start.display()))?;
+        let first_dir = if start.is_file() {
+            start.parent()
+        } else {
+            Some(start.as_path())
+        };
+
+        for dir in first_dir.into_iter().flat_map(Path::ancestors) {
+            let candidate = dir.join(CONFIG_FILE_NAME);
+            if candidate.is_file() {
+                return Self::load(&candidate);
+            }
+        }
+
+        Ok(Self::default())
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+        let root = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        Self::parse(&text, root).map_err(|e| format!("Invalid config {}: {e}", path.display()))
+    }
+
+    /// Parses a config whose relative paths are resolved against `root`.
+    pub fn parse(text: &str, root: PathBuf) -> Result<Self, String> {
+        let mut config: Self = toml::from_str(text).map_err(|e| e.to_string())?;
+        config.root = root;
+        config.loaded = LoadedRules {
+            queries: rules::query::load(&config.files(&config.rules.custom, "toml")?)?,
+            plugins: rules::plugin::load(&config.files(&config.rules.plugins, "wasm")?)?,
+        };
+        config.validate_rule_ids()?;
+        Ok(config)
+    }
+
+    /// Every rule available to this project: the built-in rules followed by
+    /// the query rules from `[rules] custom` and the plugin rules from
+    /// `[rules] plugins`.
+    pub fn rules(&self) -> Vec<Box<dyn Rule>> {
+        let mut all = rules::all_rules();
+        all.extend(self.loaded.queries.iter().map(|rule| Box::new(rule.clone()) as Box<dyn Rule>));
+        all.extend(self.loaded.plugins.iter().map(|rule| Box::new(rule.clone()) as Box<dyn Rule>));
+        all
+    }
+
+    /// Resolves `paths` against the config's directory, expanding each
+    /// directory into its files with `extension`, in name order.
+    fn files(&self, paths: &[String], extension: &str) -> Result<Vec<PathBuf>, String> {
+        let mut files = Vec::new();
+        for path in paths {
+            let path = self.root.join(path);
+            if !path.is_dir() {
+                files.push(path);
+                continue;
+            }
+            let entries = std::fs::read_dir(&path).map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+            let mut found: Vec<PathBuf> = entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|file| file.extension().is_some_and(|ext| ext == extension))
+                .collect();
+            found.sort();
+            files.extend(found);
+        }
+        Ok(files)
+    }
+
+    fn validate_rule_ids(&self) -> Result<(), String> {
+        let known: Vec<&'static str> = self.rules().iter().map(|r| r.id()).collect();
+        for (i, id) in known.iter().enumerate() {
+            if known[..i].contains(id) {
+                return Err(format!("custom rule ID `{id}` is already taken"));
+            }
+        }
+        let referenced = self
+            .rules
+            .disable
+            .iter()
+            .map(|id| ("rules.disable", id))
+            .chain(self.rules.severity.keys().map(|id| ("rules.severity", id)))
+            .chain(self.rules.config.keys().map(|id| ("rules.config", id)));
+
+        for (section, id) in referenced {
+            if !known.contains(&id.as_str()) {
+                return Err(format!(
+                    "unknown rule ID `{id}` in [{section}] (known rules: {})",
+                    known.join(", ")
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds a rule engine with disabled rules removed, per-rule options applied
+    /// through `Rule::configure`, and severity overrides in place.
+    pub fn build_engine(&self) -> Result<RuleEngine, String> {
+        let mut engine = RuleEngine::new();
+        for mut rule in self.rules() {
+            let id = rule.id();
+            if self.rules.disable.iter().any(|d| d == id) {
+                continue;
+            }
+            if let Some(options) = self.rules.config.get(id) {
+                rule.configure(options)
+                    .map_err(|e| format!("Invalid [rules.config.{id}]: {e}"))?;
+            }
+            let severity = self.rules.severity.get(id).copied().unwrap_or(rule.severity());
+            engine.register(rule, severity);
+        }
+        Ok(engine)
+    }
+
+    /// Builds the walker overrides that exclude the `[patina] ignore` globs.
+    pub fn ignore_overrides(&self) -> Result<Override, String> {
+        let mut builder = OverrideBuilder::new(&self.root);
+        for glob in &self.patina.ignore {
+            builder
+                .add(&format!("!{glob}"))
+                .map_err(|e| format!("Invalid ignore pattern `{glob}`: {e}"))?;
+        }
+        builder.build().map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_full_config() {
+        let config = Config::parse(
+            r#"
+            [patina]
+            severity = "warn"
+            format = "json"
+            ignore = ["vendor/", "*.min.js"]
+
+            [rules]
+            disable = ["slop-003"]
+
+            [rules.severity]
+            "slop-001" = "error"
+
+            [rules.config.slop-001]
+            threshold = 0.5
+            "#,
+            PathBuf::new(),
+        )
+        .unwrap();
+
+        assert!(matches!(config.patina.severity, Some(SeverityThreshold::Warn)));
+        assert!(matches!(config.patina.format, Some(OutputFormat::Json)));
+        assert_eq!(config.patina.ignore, vec!["vendor/", "*.min.js"]);
+        assert_eq!(config.rules.disable, vec!["slop-003"]);
+        assert_eq!(config.rules.severity["slop-001"], Severity::Error);
+        assert!(config.rules.config.contains_key("slop-001"));
+    }
+
+    #[test]
+    fn test_unknown_key_is_rejected() {
+        let err = Config::parse("[patina]\nseverty = \"warn\"\n", PathBuf::new()).unwrap_err();
+        assert!(err.contains("severty"), "{err}");
+    }
+
+    #[test]
+    fn test_unknown_rule_id_is_rejected() {
+        let err = Config::parse("[rules]\ndisable = [\"slop-999\"]\n", PathBuf::new()).unwrap_err();
+        assert!(err.contains("slop-999"), "{err}");
+    }
+
+    #[test]
+    fn test_custom_rules_are_loaded_once() {
+        let config = Config::parse("[rules]\ncustom = [\"rules\"]\n", PathBuf::from("tests/fixtures/config/query_rules")).unwrap();
+        let ids = |rules: Vec<Box<dyn Rule>>| -> Vec<*const u8> {
+            rules.iter().filter(|r| r.id().starts_with("house-")).map(|r| r.id().as_ptr()).collect()
+        };
+        let first = ids(config.rules());
+        assert_eq!(first.len(), 2);
+        // The same leaked strings come back, so nothing was loaded again
+        assert_eq!(ids(config.rules()), first);
+    }
+
+    #[test]
+    fn test_unknown_rule_option_is_rejected() {
+        let config = Config::parse("[rules.config.slop-001]\nthreshhold = 0.5\n", PathBuf::new()).unwrap();
+        let err = config.build_engine().err().unwrap();
+        assert!(err.contains("threshhold"), "{err}");
+    }
+}
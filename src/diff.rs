@@ -0,0 +1,311 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::types::Finding;
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Options that make `git diff` output predictable regardless of user config:
+/// no color, no external diff tool, no context lines and `a/`/`b/` prefixes.
+const GIT_DIFF: &[&str] = &[
+    "-c",
+    "core.quotePath=false",
+    "diff",
+    "--no-color",
+    "--no-ext-diff",
+    "--unified=0",
+    "--src-prefix=a/",
+    "--dst-prefix=b/",
+];
+
+/// Where `patina scan` takes the change to review from.
+pub enum DiffSource<'a> {
+    /// Changes since the merge base with a ref, including uncommitted ones.
+    Base(&'a str),
+    /// Changes staged in the index.
+    Staged,
+    /// A unified diff read from a file, or from stdin for `-`.
+    File(&'a Path),
+}
+
+/// The lines a diff added or modified, by file.
+pub struct ChangedLines {
+    /// Keyed by canonical path; 1-based line ranges in the new file, in order.
+    files: HashMap<PathBuf, Vec<Range<usize>>>,
+}
+
+impl ChangedLines {
+    /// Reads the diff for the repository containing `scan_path`. Paths in the
+    /// diff are relative to the repository root, or to the current directory
+    /// for a diff file read outside a repository.
+    pub fn load(source: &DiffSource, scan_path: &Path) -> Result<Self, String> {
+        let root = repo_root(scan_path);
+
+        let text = match source {
+            DiffSource::Base(base) => git(&root.clone()?, &[GIT_DIFF, &["--merge-base", base, "--"]].concat())?,
+            DiffSource::Staged => git(&root.clone()?, &[GIT_DIFF, &["--cached", "--"]].concat())?,
+            DiffSource::File(file) => read_diff(file)?,
+        };
+        Ok(Self::parse(&text, &root.unwrap_or_else(|_| PathBuf::from("."))))
+    }
+
+    /// Collects the added lines of each hunk in a unified diff. Removed lines
+    /// leave nothing behind to report on, so they are not tracked.
+    pub fn parse(diff: &str, root: &Path) -> Self {
+        let mut files: HashMap<PathBuf, Vec<Range<usize>>> = HashMap::new();
+        let mut current = None;
+        let mut lines = diff.lines();
+        while let Some(line) = lines.next() {
+            if let Some(target) = line.strip_prefix("+++ ") {
+                current = new_path(target).map(|path| canonical(&root.join(path)));
+                continue;
+            }
+            let Some((mut old_left, mut number, mut new_left)) = line.strip_prefix("@@ ").and_then(hunk_header) else {
+                continue;
+            };
+            // Hunk bodies are read by count, so a removed `--- ...` or added
+            // `+++ ...` line is never mistaken for a file header
+            while old_left + new_left > 0 {
+                let Some(body) = lines.next() else { break };
+                match body.as_bytes().first() {
+                    Some(b'+') => {
+                        if let Some(file) = &current {
+                            add_line(files.entry(file.clone()).or_default(), number);
+                        }
+                        number += 1;
+                        new_left = new_left.saturating_sub(1);
+                    }
+                    Some(b'-') => old_left = old_left.saturating_sub(1),
+                    Some(b'\\') => {}
+                    _ => {
+                        number += 1;
+                        old_left = old_left.saturating_sub(1);
+                        new_left = new_left.saturating_sub(1);
+                    }
+                }
+            }
+        }
+        Self { files }
+    }
+
+    /// Whether any line of the finding was added or modified, so a multi-line
+    /// finding such as a commented-out block counts when the change touches
+    /// only part of it.
+    pub fn touches(&self, finding: &Finding, source: &[u8]) -> bool {
+        let Some(ranges) = self.files.get(&canonical(&finding.file)) else {
+            return false;
+        };
+        // A trailing newline belongs to the span's last line
+        let end = finding.span.end.saturating_sub(1).max(finding.span.start);
+        let inner = source.get(finding.span.start..end).unwrap_or_default();
+        let last = finding.line + inner.iter().filter(|&&b| b == b'\n').count();
+        ranges.iter().any(|range| range.start <= last && finding.line < range.end)
+    }
+}
+
+fn add_line(ranges: &mut Vec<Range<usize>>, number: usize) {
+    match ranges.last_mut() {
+        Some(range) if range.end == number => range.end += 1,
+        _ => ranges.push(number..number + 1),
+    }
+}
+
+/// Parses `-a,b +c,d @@` into the old line count, the new start line and the
+/// new line count. Omitted counts are 1.
+fn hunk_header(header: &str) -> Option<(usize, usize, usize)> {
+    let mut parts = header.split(' ');
+    let old = parts.next()?.strip_prefix('-')?;
+    let new = parts.next()?.strip_prefix('+')?;
+    let range = |text: &str| -> Option<(usize, usize)> {
+        match text.split_once(',') {
+            Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+            None => Some((text.parse().ok()?, 1)),
+        }
+    };
+    let (_, old_count) = range(old)?;
+    let (start, new_count) = range(new)?;
+    Some((old_count, start, new_count))
+}
+
+/// The path in a `+++` line, or `None` for a deleted file.
+fn new_path(target: &str) -> Option<PathBuf> {
+    let target = if target.starts_with('"') {
+        unquote(target)?
+    } else {
+        // Plain `diff -u` appends a tab and a timestamp
+        target.split('\t').next()?.to_string()
+    };
+    if target == "/dev/null" {
+        return None;
+    }
+    Some(PathBuf::from(target.strip_prefix("b/").unwrap_or(&target)))
+}
+
+/// Decodes a path git quoted because of special characters, such as
+/// `"b/say \"hi\".js"` or octal-escaped UTF-8.
+fn unquote(quoted: &str) -> Option<String> {
+    let mut decoded = Vec::new();
+    let mut bytes = quoted.strip_prefix('"')?.bytes();
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'"' => return Some(String::from_utf8_lossy(&decoded).into_owned()),
+            b'\\' => decoded.push(match bytes.next()? {
+                b'n' => b'\n',
+                b't' => b'\t',
+                digit @ b'0'..=b'7' => {
+                    let octal = [digit, bytes.next()?, bytes.next()?];
+                    u8::from_str_radix(std::str::from_utf8(&octal).ok()?, 8).ok()?
+                }
+                other => other,
+            }),
+            _ => decoded.push(byte),
+        }
+    }
+    None
+}
+
+/// Files in the repository containing `scan_path` whose working copy differs
+/// from the index, so reading them from disk would not show the staged version.
+pub fn unstaged_files(scan_path: &Path) -> Result<HashSet<PathBuf>, String> {
+    let root = repo_root(scan_path)?;
+    let out = git(&root, &["diff", "--no-ext-diff", "--name-only", "-z"])?;
+    Ok(out
+        .split('\0')
+        .filter(|file| !file.is_empty())
+        .map(|file| canonical(&root.join(file)))
+        .collect())
+}
+
+/// The top level of the repository containing `scan_path`.
+fn repo_root(scan_path: &Path) -> Result<PathBuf, String> {
+    let dir = if scan_path.is_dir() {
+        scan_path
+    } else {
+        scan_path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."))
+    };
+    let out = git(dir, &["rev-parse", "--show-toplevel"])?;
+    Ok(PathBuf::from(out.strip_suffix('\n').unwrap_or(&out)))
+}
+
+pub fn canonical(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path)
+        .or_else(|_| std::path::absolute(path))
+        .unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn read_diff(file: &Path) -> Result<String, String> {
+    let mut bytes = Vec::new();
+    if file == Path::new("-") {
+        std::io::stdin()
+            .read_to_end(&mut bytes)
+            .map_err(|e| format!("Failed to read diff from stdin: {e}"))?;
+    } else {
+        bytes = std::fs::read(file).map_err(|e| format!("Failed to read diff {}: {e}", file.display()))?;
+    }
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Reads a file's staged content from the git index.
+pub fn read_staged(file: &Path) -> Result<Vec<u8>, String> {
+    let dir = file.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    let name = file.file_name().ok_or_else(|| format!("{} is not a file", file.display()))?;
+    // `:./name` resolves against `dir`, so no repository-relative path is needed
+    let spec = format!(":./{}", name.to_string_lossy());
+    git_output(dir, &["show", &spec])
+}
+
+/// Runs git in `dir` and returns its stdout.
+pub fn git(dir: &Path, args: &[&str]) -> Result<String, String> {
+    git_output(dir, args).map(|out| String::from_utf8_lossy(&out).into_owned())
+}
+
+fn git_output(dir: &Path, args: &[&str]) -> Result<Vec<u8>, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run git: {e}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "`git {}` failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(output.stdout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIFF: &str = "\
+diff --git a/src/app.js b/src/app.js
+index 3f1c2a4..8d0e6b7 100644
+--- a/src/app.js
++++ b/src/app.js
+@@ -4,4 +4,5 @@ export function load() {
+ const a = 1;
++const b = 2;
++const c = 3;
+-const d = 4;
+--- not a header
++++ not a header either
+ const e = 5;
+@@ -20 +21,0 @@
+-removed();
+\\ No newline at end of file
+diff --git a/src/gone.js b/src/gone.js
+deleted file mode 100644
+--- a/src/gone.js
++++ /dev/null
+@@ -1 +0,0 @@
+-gone();
+diff --git \"a/src/say \\\"hi\\\".js\" \"b/src/say \\\"hi\\\".js\"
+--- \"a/src/say \\\"hi\\\".js\"
++++ \"b/src/say \\\"hi\\\".js\"
+@@ -0,0 +1 @@
++hi();
+";
+
+    #[test]
+    fn test_parse_collects_added_lines() {
+        let changes = ChangedLines::parse(DIFF, Path::new("/repo"));
+        let ranges = |file: &str| changes.files.get(Path::new(file)).cloned();
+        // The second `+++` line is an added line inside the hunk
+        assert_eq!(ranges("/repo/src/app.js").unwrap(), [Range { start: 5, end: 8 }]);
+        assert_eq!(ranges("/repo/src/say \"hi\".js").unwrap(), [Range { start: 1, end: 2 }]);
+        assert_eq!(ranges("/repo/src/gone.js"), None);
+        assert_eq!(changes.files.len(), 2);
+    }
+
+    #[test]
+    fn test_touches_counts_partial_overlap() {
+        let changes = ChangedLines::parse(DIFF, Path::new("/repo"));
+        let source = "l1\nl2\nl3\nl4\nl5\nl6\nl7\nl8\nl9\n";
+        let finding = |file: &str, needle: &str| Finding {
+            file: PathBuf::from(file),
+            ..Finding::test_at(source, needle)
+        };
+        // Lines 3-5, ending just after line 5's newline: overlaps the hunk at 5
+        assert!(changes.touches(&finding("/repo/src/app.js", "l3\nl4\nl5\n"), source.as_bytes()));
+        // Lines 3-4 including line 4's newline: stops short of line 5
+        assert!(!changes.touches(&finding("/repo/src/app.js", "l3\nl4\n"), source.as_bytes()));
+        assert!(changes.touches(&finding("/repo/src/app.js", "l7"), source.as_bytes()));
+        assert!(!changes.touches(&finding("/repo/src/app.js", "l8"), source.as_bytes()));
+        assert!(!changes.touches(&finding("/repo/src/other.js", "l5"), source.as_bytes()));
+    }
+
+    #[test]
+    fn test_plain_unified_diff_paths() {
+        assert_eq!(new_path("app.js\t2026-01-01 10:00:00"), Some(PathBuf::from("app.js")));
+        assert_eq!(new_path("/dev/null\t1970-01-01 00:00:00"), None);
+        assert_eq!(new_path("\"b/caf\\303\\251.js\""), Some(PathBuf::from("café.js")));
+        assert_eq!(hunk_header("-1 +1 @@"), Some((1, 1, 1)));
+    }
+}
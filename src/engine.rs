@@ -2,13 +2,28 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
-use crate::rules::Rule;
-use crate::types::Finding;
-use std::path::Path;
+use crate::rules::{AnySummary, ProjectFile, Rule, RuleContext};
+use crate::suppression::Suppressions;
+use crate::types::{Finding, Severity};
+use std::path::PathBuf;
 
 #[derive(Default)]
 pub struct RuleEngine {
-    rules: Vec<Box<dyn Rule>>,
+    rules: Vec<(Box<dyn Rule>, Severity)>,
+}
+
+/// One file's results, held until the project-level pass has run: its
+/// findings can still be suppressed by the file's directives, and only then
+/// is it known which directives went unused.
+pub struct FileAnalysis {
+    pub path: PathBuf,
+    pub source: Vec<u8>,
+    findings: Vec<Finding>,
+    suppressions: Suppressions,
+    /// What each project rule collected from the file, by rule index.
+    summaries: Vec<(usize, AnySummary)>,
+    /// Rules that failed on the file, which fail the whole scan.
+    pub errors: Vec<String>,
 }
 
 impl RuleEngine {
@@ -16,30 +31,208 @@ impl RuleEngine {
         Self::default()
     }
 
-    pub fn register(&mut self, rule: Box<dyn Rule>) {
-        self.rules.push(rule);
+    /// Registers a rule whose findings are reported at `severity`, which is the
+    /// rule's default unless overridden in `.patina.toml`.
+    pub fn register(&mut self, rule: Box<dyn Rule>, severity: Severity) {
+        self.rules.push((rule, severity));
     }
 
-    pub fn analyze(
-        &self,
-        source: &[u8],
-        tree: &tree_sitter::Tree,
-        file_path: &Path,
-    ) -> Vec<Finding> {
-        let mut findings: Vec<Finding> = self
+    /// Runs every rule's per-file check, applies the file's suppressions, and
+    /// collects the file's summaries for project rules.
+    pub fn analyze(&self, ctx: &RuleContext) -> FileAnalysis {
+        let mut findings = Vec::new();
+        let mut errors = Vec::new();
+        for (rule, severity) in &self.rules {
+            match rule.try_check(ctx) {
+                Ok(found) => findings.extend(Self::label(rule.as_ref(), *severity, found)),
+                Err(e) => errors.push(format!("Rule {} failed on {}: {e}", rule.id(), ctx.file_path.display())),
+            }
+        }
+
+        let mut suppressions = Suppressions::parse(ctx);
+        findings.retain(|finding| !suppressions.suppresses(finding, ctx.source));
+
+        let summaries = self
             .rules
             .iter()
-            .flat_map(|rule| {
-                let mut rule_findings = rule.check(source, tree, file_path);
-                for finding in &mut rule_findings {
-                    finding.rule_id = rule.id();
-                    finding.severity = rule.severity();
-                    finding.message = format!("{}: {}", rule.name(), finding.message);
+            .enumerate()
+            .filter_map(|(i, (rule, _))| Some((i, rule.project()?.collect_any(ctx)?)))
+            .collect();
+        FileAnalysis {
+            path: ctx.file_path.to_path_buf(),
+            source: ctx.source.to_vec(),
+            findings,
+            suppressions,
+            summaries,
+            errors,
+        }
+    }
+
+    /// Finalizes every project rule over the collected summaries, then returns
+    /// all findings grouped by file in input order and sorted by position.
+    pub fn finish(&self, files: &mut [FileAnalysis]) -> Vec<Finding> {
+        for (i, (rule, severity)) in self.rules.iter().enumerate() {
+            let Some(project) = rule.project() else {
+                continue;
+            };
+            let summaries: Vec<ProjectFile<AnySummary>> = files
+                .iter_mut()
+                .filter_map(|file| {
+                    let at = file.summaries.iter().position(|(rule, _)| *rule == i)?;
+                    let (_, summary) = file.summaries.swap_remove(at);
+                    Some(ProjectFile {
+                        path: file.path.clone(),
+                        summary,
+                    })
+                })
+                .collect();
+            for finding in Self::label(rule.as_ref(), *severity, project.finalize_any(summaries)) {
+                if let Some(file) = files.iter_mut().find(|file| file.path == finding.file)
+                    && !file.suppressions.suppresses(&finding, &file.source)
+                {
+                    file.findings.push(finding);
                 }
-                rule_findings
+            }
+        }
+
+        let active_rules: Vec<&str> = self.rules.iter().map(|(rule, _)| rule.id()).collect();
+        let mut all = Vec::new();
+        for file in files {
+            let mut findings = std::mem::take(&mut file.findings);
+            findings.extend(file.suppressions.unused(&file.source, &file.path, &active_rules));
+            findings.sort_by(|a, b| a.line.cmp(&b.line).then(a.column.cmp(&b.column)));
+            all.extend(findings);
+        }
+        all
+    }
+
+    /// Stamps a rule's identity and configured severity onto its findings.
+    fn label(rule: &dyn Rule, severity: Severity, mut findings: Vec<Finding>) -> Vec<Finding> {
+        for finding in &mut findings {
+            finding.rule_id = rule.id();
+            finding.severity = severity;
+            finding.message = format!("{}: {}", rule.name(), finding.message);
+        }
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::Language;
+    use crate::rules::{DynProjectRule, ProjectRule, RuleDocs};
+    use crate::types::Category;
+    use std::path::Path;
+
+    /// Collects its own ID from every file, and reports each file it saw plus
+    /// one that was never scanned, all on line 2.
+    struct EveryFile(&'static str);
+
+    impl Rule for EveryFile {
+        fn id(&self) -> &'static str {
+            self.0
+        }
+        fn name(&self) -> &'static str {
+            "Every File"
+        }
+        fn description(&self) -> &'static str {
+            ""
+        }
+        fn severity(&self) -> Severity {
+            Severity::Info
+        }
+        fn category(&self) -> Category {
+            Category::Uniformity
+        }
+        fn docs(&self) -> RuleDocs {
+            RuleDocs {
+                rationale: "Test rule.",
+                heuristic: "Reports every file.",
+                flagged_example: "",
+                not_flagged_example: "",
+                options: &[],
+                false_positives: &[],
+            }
+        }
+        fn check(&self, _ctx: &RuleContext) -> Vec<Finding> {
+            Vec::new()
+        }
+        fn project(&self) -> Option<&dyn DynProjectRule> {
+            Some(self)
+        }
+    }
+
+    impl ProjectRule for EveryFile {
+        type Summary = &'static str;
+
+        fn collect(&self, _ctx: &RuleContext) -> Option<&'static str> {
+            Some(self.0)
+        }
+
+        fn finalize(&self, files: &[ProjectFile<&'static str>]) -> Vec<Finding> {
+            let paths = files.iter().map(|file| {
+                assert_eq!(file.summary, self.0, "summary routed to the wrong rule");
+                file.path.clone()
+            });
+            paths
+                .chain([PathBuf::from("outside.js")])
+                .map(|file| Finding {
+                    rule_id: "",
+                    message: String::new(),
+                    severity: Severity::Info,
+                    file,
+                    line: 2,
+                    column: 1,
+                    span: 0..0,
+                    suggestion: None,
+                    fix: None,
+                    metrics: None,
+                    related: Vec::new(),
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_project_findings_are_routed_and_suppressed_by_file() {
+        let mut engine = RuleEngine::new();
+        engine.register(Box::new(EveryFile("test-001")), Severity::Info);
+        engine.register(Box::new(EveryFile("test-002")), Severity::Warn);
+
+        let parser = Language::JavaScript.parser().unwrap();
+        let sources = [
+            ("a.js", "run();\nrun();\n"),
+            ("b.js", "// patina-ignore-next-line test-001\nrun();\n"),
+        ];
+        let mut files: Vec<FileAnalysis> = sources
+            .iter()
+            .map(|(path, source)| {
+                let tree = parser.parse(source.as_bytes()).unwrap();
+                engine.analyze(&RuleContext {
+                    source: source.as_bytes(),
+                    tree: &tree,
+                    file_path: Path::new(path),
+                    language: Language::JavaScript,
+                    node_types: parser.node_types(),
+                })
             })
             .collect();
-        findings.sort_by(|a, b| a.line.cmp(&b.line).then(a.column.cmp(&b.column)));
-        findings
+
+        let findings: Vec<(&str, String, Severity)> = engine
+            .finish(&mut files)
+            .into_iter()
+            .map(|f| (f.rule_id, f.file.display().to_string(), f.severity))
+            .collect();
+        // The directive in b.js was used, so it is not reported, and nothing
+        // is reported for outside.js, which was not scanned
+        assert_eq!(
+            findings,
+            vec![
+                ("test-001", "a.js".to_string(), Severity::Info),
+                ("test-002", "a.js".to_string(), Severity::Warn),
+                ("test-002", "b.js".to_string(), Severity::Warn),
+            ]
+        );
     }
 }
@@ -0,0 +1,297 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::baseline::normalize_path;
+use crate::parsers::Language;
+use crate::types::{Edit, Finding};
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// Lines of unchanged context around each hunk in `--dry-run` diffs.
+const DIFF_CONTEXT: usize = 3;
+
+/// The result of applying every fix for one file.
+pub struct FileFix {
+    pub path: PathBuf,
+    pub original: Vec<u8>,
+    pub fixed: Vec<u8>,
+    /// Edits that were applied, in source order.
+    pub edits: Vec<Edit>,
+    /// Findings whose fix overlapped an edit that was already accepted.
+    pub conflicts: Vec<Finding>,
+}
+
+impl FileFix {
+    /// Renders the change as a unified diff with `a/` and `b/` path prefixes.
+    pub fn unified_diff(&self) -> String {
+        let path = normalize_path(&self.path);
+        let path = path.trim_start_matches('/');
+        let mut out = format!("--- a/{path}\n+++ b/{path}\n");
+        for hunk in hunks(&self.original, &self.edits) {
+            out.push_str(&hunk);
+        }
+        out
+    }
+}
+
+/// Applies the fixes attached to `findings`, one `FileFix` per file that has
+/// any. A file whose fixed source has more tree-sitter error nodes than the
+/// original is rejected with an `Err` and must be left untouched.
+pub fn fix_files(
+    findings: &[Finding],
+    sources: &HashMap<PathBuf, Vec<u8>>,
+) -> Vec<Result<FileFix, String>> {
+    let mut by_file: BTreeMap<&Path, Vec<&Finding>> = BTreeMap::new();
+    for finding in findings.iter().filter(|f| f.fix.is_some()) {
+        by_file.entry(&finding.file).or_default().push(finding);
+    }
+
+    by_file
+        .into_iter()
+        .filter_map(|(path, findings)| {
+            let source = sources.get(path)?;
+            Some(fix_file(path, source, findings))
+        })
+        .collect()
+}
+
+fn fix_file(path: &Path, source: &[u8], mut findings: Vec<&Finding>) -> Result<FileFix, String> {
+    findings.sort_by_key(|f| {
+        let span = &f.fix.as_ref().expect("only findings with fixes").span;
+        (span.start, span.end)
+    });
+
+    let mut edits: Vec<Edit> = Vec::new();
+    let mut conflicts = Vec::new();
+    for finding in findings {
+        let edit = finding.fix.clone().expect("only findings with fixes");
+        match edits.last() {
+            // Two rules asking for the same edit is not a conflict
+            Some(last) if *last == edit => {}
+            Some(last) if edit.span.start < last.span.end => conflicts.push(finding.clone()),
+            _ => edits.push(edit),
+        }
+    }
+
+    let mut fixed = source.to_vec();
+    for edit in edits.iter().rev() {
+        fixed.splice(edit.span.clone(), edit.replacement.bytes());
+    }
+
+    let errors_before = count_parse_errors(path, source)?;
+    let errors_after = count_parse_errors(path, &fixed)?;
+    if errors_after > errors_before {
+        return Err(format!(
+            "{}: fixes would introduce {} syntax error(s); file left unchanged",
+            path.display(),
+            errors_after - errors_before
+        ));
+    }
+
+    Ok(FileFix {
+        path: path.to_path_buf(),
+        original: source.to_vec(),
+        fixed,
+        edits,
+        conflicts,
+    })
+}
+
+/// Counts ERROR and MISSING nodes in the parse tree of `source`.
+fn count_parse_errors(path: &Path, source: &[u8]) -> Result<usize, String> {
+    let language =
+        Language::from_path(path).ok_or_else(|| format!("No parser for {}", path.display()))?;
+    let parser = language
+        .parser()
+        .map_err(|e| format!("Error initializing {} parser: {e}", language.id()))?;
+    let tree = parser
+        .parse(source)
+        .map_err(|e| format!("Parse error in {}: {e}", path.display()))?;
+
+    let mut count = 0;
+    let mut cursor = tree.walk();
+    loop {
+        let node = cursor.node();
+        if node.is_error() || node.is_missing() {
+            count += 1;
+        }
+
+        if cursor.goto_first_child() {
+            continue;
+        }
+        if cursor.goto_next_sibling() {
+            continue;
+        }
+        loop {
+            if !cursor.goto_parent() {
+                return Ok(count);
+            }
+            if cursor.goto_next_sibling() {
+                break;
+            }
+        }
+    }
+}
+
+/// A run of original lines replaced by new lines.
+struct Change {
+    /// 0-based indices into the original lines.
+    old: Range<usize>,
+    new: Vec<String>,
+}
+
+/// Builds the `@@` hunks for `edits`, which must be sorted and non-overlapping.
+fn hunks(source: &[u8], edits: &[Edit]) -> Vec<String> {
+    let text = String::from_utf8_lossy(source);
+    let lines: Vec<&str> = text.split_inclusive('\n').collect();
+    let mut line_starts = Vec::with_capacity(lines.len() + 1);
+    let mut offset = 0;
+    for line in &lines {
+        line_starts.push(offset);
+        offset += line.len();
+    }
+    line_starts.push(offset);
+    let line_of = |byte: usize| line_starts.partition_point(|&start| start <= byte).saturating_sub(1);
+
+    // Edits touching the same line become one change covering whole lines
+    let mut changes: Vec<Change> = Vec::new();
+    let mut group: Vec<&Edit> = Vec::new();
+    let mut group_lines = 0..0;
+    for edit in edits {
+        let first = line_of(edit.span.start);
+        let last = if edit.span.is_empty() { first } else { line_of(edit.span.end - 1) };
+        if !group.is_empty() && first >= group_lines.end {
+            changes.push(change(&text, &line_starts, group_lines.clone(), &group));
+            group.clear();
+        }
+        if group.is_empty() {
+            group_lines.start = first;
+        }
+        group_lines.end = last + 1;
+        group.push(edit);
+    }
+    if !group.is_empty() {
+        changes.push(change(&text, &line_starts, group_lines, &group));
+    }
+
+    let mut hunks = Vec::new();
+    let mut shift: isize = 0;
+    let mut i = 0;
+    while i < changes.len() {
+        // Merge changes whose context windows would touch
+        let mut j = i + 1;
+        while j < changes.len() && changes[j].old.start - changes[j - 1].old.end <= 2 * DIFF_CONTEXT {
+            j += 1;
+        }
+
+        let old_start = changes[i].old.start.saturating_sub(DIFF_CONTEXT);
+        let old_end = (changes[j - 1].old.end + DIFF_CONTEXT).min(lines.len());
+        let new_start = (old_start as isize + shift) as usize;
+
+        let mut body = String::new();
+        let mut new_len = 0;
+        let mut line = old_start;
+        for change in &changes[i..j] {
+            for context in &lines[line..change.old.start] {
+                push_line(&mut body, ' ', context);
+            }
+            for removed in &lines[change.old.clone()] {
+                push_line(&mut body, '-', removed);
+            }
+            for added in &change.new {
+                push_line(&mut body, '+', added);
+            }
+            new_len += change.old.start - line + change.new.len();
+            shift += change.new.len() as isize - change.old.len() as isize;
+            line = change.old.end;
+        }
+        for context in &lines[line..old_end] {
+            push_line(&mut body, ' ', context);
+        }
+        new_len += old_end - line;
+
+        hunks.push(format!(
+            "@@ -{} +{} @@\n{body}",
+            hunk_range(old_start, old_end - old_start),
+            hunk_range(new_start, new_len)
+        ));
+        i = j;
+    }
+    hunks
+}
+
+fn change(text: &str, line_starts: &[usize], lines: Range<usize>, edits: &[&Edit]) -> Change {
+    let base = line_starts[lines.start];
+    let mut replaced = text[base..line_starts[lines.end]].to_string();
+    for edit in edits.iter().rev() {
+        replaced.replace_range(edit.span.start - base..edit.span.end - base, &edit.replacement);
+    }
+    Change {
+        old: lines,
+        new: replaced.split_inclusive('\n').map(str::to_string).collect(),
+    }
+}
+
+fn push_line(body: &mut String, marker: char, line: &str) {
+    body.push(marker);
+    body.push_str(line);
+    if !line.ends_with('\n') {
+        body.push_str("\n\\ No newline at end of file\n");
+    }
+}
+
+/// Formats a 1-based `start,len` range; empty ranges name the line before them.
+fn hunk_range(start: usize, len: usize) -> String {
+    if len == 0 {
+        format!("{start},0")
+    } else {
+        format!("{},{len}", start + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fix(source: &str, findings: &[Finding]) -> Result<FileFix, String> {
+        let sources = HashMap::from([(PathBuf::from("app.js"), source.as_bytes().to_vec())]);
+        fix_files(findings, &sources).pop().unwrap()
+    }
+
+    #[test]
+    fn test_overlapping_edits_are_conflicts() {
+        let source = "// one\n// two\nrun();\n";
+        let mut group = Finding::test_at(source, "// one\n// two").with_fix(source);
+        group.rule_id = "slop-004";
+        let single = Finding::test_at(source, "// two").with_fix(source);
+        let result = fix(source, &[group, single]).unwrap();
+
+        assert_eq!(String::from_utf8(result.fixed).unwrap(), "run();\n");
+        assert_eq!(result.conflicts.len(), 1);
+        assert_eq!(result.conflicts[0].rule_id, "slop-001");
+    }
+
+    #[test]
+    fn test_fix_introducing_parse_errors_is_rejected() {
+        let source = "const a = {\n  // note\n};\n";
+        let mut finding = Finding::test_at(source, "// note");
+        finding.fix = Some(Edit {
+            span: 0..source.find('{').unwrap() + 1,
+            replacement: String::new(),
+        });
+        let err = fix(source, &[finding]).err().unwrap();
+        assert!(err.contains("syntax error"), "{err}");
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let source = "a();\nb();\n// Call c\nc();\nd();\ne();\nf();\ng();\n";
+        let result = fix(source, &[Finding::test_at(source, "// Call c").with_fix(source)]).unwrap();
+        assert_eq!(
+            result.unified_diff(),
+            "--- a/app.js\n+++ b/app.js\n@@ -1,6 +1,5 @@\n a();\n b();\n-// Call c\n c();\n d();\n e();\n"
+        );
+    }
+}
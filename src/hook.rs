@@ -0,0 +1,64 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::diff::git;
+use std::path::{Path, PathBuf};
+
+/// Marks hooks patina wrote, so reinstalling replaces them without `--force`.
+const MARKER: &str = "# Installed by `patina hook install`";
+
+const PRE_COMMIT: &str = "\
+#!/bin/sh
+# Installed by `patina hook install`
+# Blocks the commit when the staged changes have findings at or above the
+# configured severity. Skip it once with `git commit --no-verify`.
+exec patina scan . --staged
+";
+
+/// Writes the pre-commit hook for the repository containing the current
+/// directory and returns its path. `core.hooksPath` and worktrees are
+/// honoured because git resolves the path.
+pub fn install(force: bool) -> Result<PathBuf, String> {
+    let out = git(Path::new("."), &["rev-parse", "--git-path", "hooks/pre-commit"])?;
+    let path = PathBuf::from(out.strip_suffix('\n').unwrap_or(&out));
+
+    if let Ok(existing) = std::fs::read_to_string(&path)
+        && !force
+        && !existing.contains(MARKER)
+    {
+        return Err(format!(
+            "{} already exists; re-run with --force to replace it",
+            path.display()
+        ));
+    }
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {e}", dir.display()))?;
+    }
+    std::fs::write(&path, PRE_COMMIT).map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+    make_executable(&path)?;
+    Ok(path)
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<(), String> {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+        .map_err(|e| format!("Failed to make {} executable: {e}", path.display()))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<(), String> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pre_commit_carries_marker() {
+        assert!(PRE_COMMIT.starts_with("#!/bin/sh\n"));
+        assert!(PRE_COMMIT.contains(MARKER));
+    }
+}
@@ -2,20 +2,29 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
+mod baseline;
 mod cli;
+mod config;
+mod diff;
 mod engine;
+mod fix;
+mod hook;
 mod parsers;
 mod reporters;
 mod rules;
 mod scanner;
+mod suppression;
 mod tokens;
 mod types;
 
+use baseline::{Baseline, BaselineDiff};
 use clap::Parser;
-use cli::{Cli, Command, OutputFormat, SeverityThreshold};
-use engine::RuleEngine;
+use cli::{BaselineCommand, Cli, Command, HookCommand, OutputFormat, SeverityThreshold};
+use config::Config;
+use diff::{ChangedLines, DiffSource};
 use reporters::Reporter;
-use std::collections::HashMap;
+use scanner::{Analysis, Contents};
+use std::path::Path;
 use std::process;
 use types::Severity;
 
@@ -27,59 +36,64 @@ fn main() {
             ref path,
             ref format,
             severity_threshold,
+            ref baseline,
+            ref diff,
+            staged,
+            ref diff_file,
+            jobs,
         } => {
-            let files = scanner::scan_files(path);
+            let config = Config::discover(path).unwrap_or_else(|e| exit_with_config_error(&e));
+            let format = format
+                .clone()
+                .or_else(|| config.patina.format.clone())
+                .unwrap_or(OutputFormat::Terminal);
+            let severity_threshold = severity_threshold
+                .or(config.patina.severity)
+                .unwrap_or(SeverityThreshold::Info);
 
-            // Build rule engine
-            let mut engine = RuleEngine::new();
-            for rule in rules::all_rules() {
-                engine.register(rule);
-            }
-
-            // Analyze each file
-            let mut all_findings = Vec::new();
-            let mut sources = HashMap::new();
-            let mut parser_cache: HashMap<String, Box<dyn parsers::LanguageParser>> =
-                HashMap::new();
-            for file_path in &files {
-                let source = match std::fs::read(file_path) {
-                    Ok(s) => s,
-                    Err(e) => {
-                        eprintln!("Error reading {}: {e}", file_path.display());
-                        continue;
-                    }
-                };
+            let diff_source = match (diff, staged, diff_file) {
+                (Some(base), _, _) => Some(DiffSource::Base(base)),
+                (_, true, _) => Some(DiffSource::Staged),
+                (_, _, Some(file)) => Some(DiffSource::File(file)),
+                _ => None,
+            };
+            let changes = diff_source.map(|source| {
+                ChangedLines::load(&source, path).unwrap_or_else(|e| {
+                    eprintln!("Error: {e}");
+                    process::exit(2);
+                })
+            });
 
-                let ext = match file_path.extension().and_then(|e| e.to_str()) {
-                    Some(e) => e,
-                    None => continue,
-                };
+            let contents = if staged {
+                Contents::Index(diff::unstaged_files(path).unwrap_or_else(|e| {
+                    eprintln!("Error: {e}");
+                    process::exit(2);
+                }))
+            } else {
+                Contents::WorkingTree
+            };
 
-                if !parser_cache.contains_key(ext) {
-                    match parsers::javascript::parser_for_extension(ext) {
-                        Some(Ok(p)) => {
-                            parser_cache.insert(ext.to_string(), p);
-                        }
-                        Some(Err(e)) => {
-                            eprintln!("Error initializing parser for .{ext}: {e}");
-                            continue;
-                        }
-                        None => continue,
-                    }
-                }
-                let parser = &parser_cache[ext];
+            // Every file is analyzed so project rules can compare changed code
+            // with the rest; only the findings are narrowed to the change
+            let Analysis {
+                findings: mut all_findings,
+                sources,
+            } = analyze_path(path, &config, jobs, &contents);
 
-                let tree = match parser.parse(&source) {
-                    Ok(t) => t,
-                    Err(e) => {
-                        eprintln!("Parse error in {}: {e}", file_path.display());
-                        continue;
-                    }
-                };
+            // Hide findings already recorded in the baseline
+            if let Some(baseline_path) = baseline {
+                let baseline = Baseline::load(baseline_path).unwrap_or_else(|e| {
+                    eprintln!("Error: {e}");
+                    process::exit(2);
+                });
+                let diff = baseline.diff(all_findings, &sources);
+                print_baseline_summary(&diff);
+                all_findings = diff.new;
+            }
 
-                let findings = engine.analyze(&source, &tree, file_path);
-                all_findings.extend(findings);
-                sources.insert(file_path.clone(), source);
+            // Keep only findings on lines the change touched
+            if let Some(changes) = &changes {
+                all_findings.retain(|f| changes.touches(f, sources.get(&f.file).map_or(&[], Vec::as_slice)));
             }
 
             // Filter by severity threshold
@@ -89,6 +103,10 @@ fn main() {
             let reporter: Box<dyn Reporter> = match format {
                 OutputFormat::Terminal => Box::new(reporters::terminal::TerminalReporter),
                 OutputFormat::Json => Box::new(reporters::json::JsonReporter),
+                OutputFormat::Sarif => {
+                    let rules = config.rules();
+                    Box::new(reporters::sarif::SarifReporter::new(&rules))
+                }
             };
 
             if let Err(e) = reporter.report(&all_findings, &sources) {
@@ -103,8 +121,115 @@ fn main() {
             }
         }
 
-        Command::Rules { ref format } => {
-            let all = rules::all_rules();
+        Command::Fix {
+            ref path,
+            dry_run,
+            jobs,
+        } => {
+            let config = Config::discover(path).unwrap_or_else(|e| exit_with_config_error(&e));
+            let Analysis { findings, sources } = analyze_path(path, &config, jobs, &Contents::WorkingTree);
+
+            let mut fixed_findings = 0;
+            let mut fixed_files = 0;
+            let mut failed = false;
+            for result in fix::fix_files(&findings, &sources) {
+                let file_fix = match result {
+                    Ok(file_fix) => file_fix,
+                    Err(e) => {
+                        eprintln!("Error: {e}");
+                        failed = true;
+                        continue;
+                    }
+                };
+                for conflict in &file_fix.conflicts {
+                    eprintln!(
+                        "Skipped overlapping fix: {}:{} [{}]",
+                        file_fix.path.display(),
+                        conflict.line,
+                        conflict.rule_id
+                    );
+                }
+
+                if dry_run {
+                    print!("{}", file_fix.unified_diff());
+                } else if let Err(e) = std::fs::write(&file_fix.path, &file_fix.fixed) {
+                    eprintln!("Error: failed to write {}: {e}", file_fix.path.display());
+                    failed = true;
+                    continue;
+                }
+                fixed_findings += file_fix.edits.len();
+                fixed_files += 1;
+            }
+
+            eprintln!(
+                "{} {fixed_findings} fix(es) in {fixed_files} file(s)",
+                if dry_run { "Would apply" } else { "Applied" }
+            );
+            if failed {
+                process::exit(2);
+            }
+            // Like `scan`, a dry run exits 1 when there is something to do
+            if dry_run && fixed_findings > 0 {
+                process::exit(1);
+            }
+        }
+
+        Command::Baseline {
+            command:
+                BaselineCommand::Create {
+                    ref path,
+                    ref output,
+                    jobs,
+                },
+        } => {
+            let config = Config::discover(path).unwrap_or_else(|e| exit_with_config_error(&e));
+            let Analysis { findings, sources } = analyze_path(path, &config, jobs, &Contents::WorkingTree);
+
+            let baseline = Baseline::from_findings(&findings, &sources);
+            if let Err(e) = baseline.save(output) {
+                eprintln!("Error: {e}");
+                process::exit(2);
+            }
+            eprintln!(
+                "Wrote {} finding(s) to {}",
+                baseline.findings.len(),
+                output.display()
+            );
+        }
+
+        Command::Hook {
+            command: HookCommand::Install { force },
+        } => match hook::install(force) {
+            Ok(path) => eprintln!("Installed pre-commit hook at {}", path.display()),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                process::exit(2);
+            }
+        },
+
+        Command::Rules {
+            ref format,
+            ref explain,
+        } => {
+            let config = Config::discover(Path::new(".")).unwrap_or_else(|e| exit_with_config_error(&e));
+            let all = config.rules();
+            if let Some(id) = explain {
+                let Some(rule) = all.iter().find(|r| r.id() == id) else {
+                    let known: Vec<&str> = all.iter().map(|r| r.id()).collect();
+                    eprintln!("Error: unknown rule `{id}` (known rules: {})", known.join(", "));
+                    process::exit(2);
+                };
+                match format {
+                    OutputFormat::Terminal => print_rule_explanation(rule.as_ref()),
+                    OutputFormat::Json | OutputFormat::Sarif => println!(
+                        "{}",
+                        serde_json::to_string_pretty(&rule_json(rule.as_ref()))
+                            .expect("JSON serialization failed")
+                    ),
+                }
+                return;
+            }
+
             match format {
                 OutputFormat::Terminal => {
                     println!("{:<12} {:<24} {:<10} Description", "ID", "Name", "Severity");
@@ -120,27 +245,138 @@ fn main() {
                     }
                 }
                 OutputFormat::Json => {
-                    let entries: Vec<serde_json::Value> = all
-                        .iter()
-                        .map(|rule| {
-                            serde_json::json!({
-                                "id": rule.id(),
-                                "name": rule.name(),
-                                "severity": format!("{}", rule.severity()),
-                                "description": rule.description(),
-                            })
-                        })
-                        .collect();
+                    let entries: Vec<serde_json::Value> =
+                        all.iter().map(|rule| rule_json(rule.as_ref())).collect();
                     println!(
                         "{}",
                         serde_json::to_string_pretty(&entries).expect("JSON serialization failed")
                     );
                 }
+                OutputFormat::Sarif => {
+                    let descriptors: Vec<serde_json::Value> = reporters::sarif::rule_descriptors(&all)
+                        .into_iter()
+                        .map(|(_, descriptor)| descriptor)
+                        .collect();
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&descriptors)
+                            .expect("JSON serialization failed")
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn rule_json(rule: &dyn rules::Rule) -> serde_json::Value {
+    serde_json::json!({
+        "id": rule.id(),
+        "name": rule.name(),
+        "severity": format!("{}", rule.severity()),
+        "category": rule.category(),
+        "description": rule.description(),
+        "docs": rule.docs(),
+    })
+}
+
+fn print_rule_explanation(rule: &dyn rules::Rule) {
+    let docs = rule.docs();
+    let indent = |text: &str| {
+        text.lines()
+            .map(|line| format!("    {line}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let wrap = |text: &str| {
+        let mut lines = vec![String::new()];
+        for word in text.split_whitespace() {
+            let line = lines.last_mut().expect("lines is never empty");
+            if !line.is_empty() && line.len() + word.len() >= 76 {
+                lines.push(word.to_string());
+            } else {
+                if !line.is_empty() {
+                    line.push(' ');
+                }
+                line.push_str(word);
             }
         }
+        indent(&lines.join("\n"))
+    };
+
+    println!("{} — {} ({})", rule.id(), rule.name(), rule.severity());
+    println!("{}\n", rule.description());
+    println!("Why it matters:\n{}\n", wrap(docs.rationale));
+    println!("How it works:\n{}\n", wrap(docs.heuristic));
+    // Custom rules may leave out their examples and known false positives
+    if !docs.flagged_example.is_empty() {
+        println!("Flagged:\n{}\n", indent(docs.flagged_example));
+    }
+    if !docs.not_flagged_example.is_empty() {
+        println!("Not flagged:\n{}\n", indent(docs.not_flagged_example));
+    }
+
+    if docs.options.is_empty() {
+        println!("Options: none\n");
+    } else {
+        println!("Options ([rules.config.{}] in .patina.toml):", rule.id());
+        for option in docs.options {
+            println!(
+                "    {:<22} default {:<6} {}",
+                option.name, option.default, option.description
+            );
+        }
+        println!();
+    }
+
+    if !docs.false_positives.is_empty() {
+        println!("Known false positives:");
+        for case in docs.false_positives {
+            println!("    - {case}");
+        }
     }
 }
 
+/// Discovers, parses and analyzes every supported file under `path`, reading
+/// them from `contents`. Returns the findings together with the source of each
+/// analyzed file.
+fn analyze_path(path: &Path, config: &Config, jobs: usize, contents: &Contents) -> Analysis {
+    let overrides = config
+        .ignore_overrides()
+        .unwrap_or_else(|e| exit_with_config_error(&e));
+    let files = scanner::scan_files(path, &overrides);
+
+    let engine = config.build_engine().unwrap_or_else(|e| exit_with_config_error(&e));
+
+    scanner::analyze_files(&files, &engine, jobs, contents).unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        process::exit(2);
+    })
+}
+
+/// Prints baseline statistics to stderr so machine-readable stdout stays clean.
+fn print_baseline_summary(diff: &BaselineDiff) {
+    eprintln!(
+        "Baseline: {} known finding(s) hidden, {} new",
+        diff.matched,
+        diff.new.len()
+    );
+    if !diff.fixed.is_empty() {
+        eprintln!(
+            "{} baseline entr{} no longer match and can be pruned with `patina baseline create`:",
+            diff.fixed.len(),
+            if diff.fixed.len() == 1 { "y" } else { "ies" }
+        );
+        for entry in &diff.fixed {
+            eprintln!("  {}:{} [{}]", entry.file, entry.line, entry.rule_id);
+        }
+    }
+}
+
+fn exit_with_config_error(message: &str) -> ! {
+    eprintln!("Error: {message}");
+    process::exit(2);
+}
+
 /// Returns true if the finding's severity meets or exceeds the threshold.
 /// Ordering: error > warn > info
 fn severity_passes(severity: Severity, threshold: SeverityThreshold) -> bool {
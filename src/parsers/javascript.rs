@@ -2,20 +2,85 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
-use super::LanguageParser;
+use super::{LanguageParser, NodeTypeMap, SemanticNode};
 use std::cell::RefCell;
 use tree_sitter::{Language, Parser};
 
+pub const JS_EXTENSIONS: &[&str] = &["js", "jsx"];
+pub const TS_EXTENSIONS: &[&str] = &["ts"];
+pub const TSX_EXTENSIONS: &[&str] = &["tsx"];
+
 pub struct JsParser {
     parser: RefCell<Parser>,
+    node_types: NodeTypeMap,
 }
 
 pub struct TsParser {
     parser: RefCell<Parser>,
+    node_types: NodeTypeMap,
 }
 
 pub struct TsxParser {
     parser: RefCell<Parser>,
+    node_types: NodeTypeMap,
+}
+
+/// JavaScript and TypeScript share node names for everything rules use.
+fn node_types() -> NodeTypeMap {
+    NodeTypeMap::from([
+        (SemanticNode::Comment, vec!["comment"]),
+        (
+            SemanticNode::Identifier,
+            vec![
+                "identifier",
+                "property_identifier",
+                "shorthand_property_identifier",
+                "shorthand_property_identifier_pattern",
+            ],
+        ),
+        (SemanticNode::CatchClause, vec!["catch_clause"]),
+        (SemanticNode::Block, vec!["statement_block"]),
+        (SemanticNode::CallExpression, vec!["call_expression"]),
+        (SemanticNode::Closure, vec!["arrow_function", "function_expression", "generator_function"]),
+        (
+            SemanticNode::Function,
+            vec!["function_declaration", "generator_function_declaration", "method_definition"],
+        ),
+        (
+            SemanticNode::ControlFlow,
+            vec![
+                "if_statement",
+                "for_statement",
+                "for_in_statement",
+                "while_statement",
+                "do_statement",
+                "switch_statement",
+                "try_statement",
+            ],
+        ),
+        (SemanticNode::ElseClause, vec!["else_clause"]),
+        (
+            SemanticNode::Branch,
+            vec![
+                "if_statement",
+                "for_statement",
+                "for_in_statement",
+                "while_statement",
+                "do_statement",
+                "switch_case",
+                "catch_clause",
+                "ternary_expression",
+                "&&",
+                "||",
+                "??",
+            ],
+        ),
+        (SemanticNode::DebuggerStatement, vec!["debugger_statement"]),
+        (SemanticNode::Return, vec!["return_statement"]),
+        (SemanticNode::Throw, vec!["throw_statement"]),
+        (SemanticNode::RestPattern, vec!["rest_pattern"]),
+        (SemanticNode::DestructuringPattern, vec!["object_pattern", "array_pattern"]),
+    ])
 }
 
 impl JsParser {
@@ -24,7 +89,7 @@ impl JsParser {
         parser
             .set_language(&Language::from(tree_sitter_javascript::LANGUAGE))
             .map_err(|e| format!("Failed to set JS language: {e}"))?;
-        Ok(Self { parser: RefCell::new(parser) })
+        Ok(Self { parser: RefCell::new(parser), node_types: node_types() })
     }
 }
 
@@ -34,7 +99,7 @@ impl TsParser {
         parser
             .set_language(&Language::from(tree_sitter_typescript::LANGUAGE_TYPESCRIPT))
             .map_err(|e| format!("Failed to set TS language: {e}"))?;
-        Ok(Self { parser: RefCell::new(parser) })
+        Ok(Self { parser: RefCell::new(parser), node_types: node_types() })
     }
 }
 
@@ -44,42 +109,69 @@ impl TsxParser {
         parser
             .set_language(&Language::from(tree_sitter_typescript::LANGUAGE_TSX))
             .map_err(|e| format!("Failed to set TSX language: {e}"))?;
-        Ok(Self { parser: RefCell::new(parser) })
+        Ok(Self { parser: RefCell::new(parser), node_types: node_types() })
     }
 }
 
 impl LanguageParser for JsParser {
+    fn language_id(&self) -> &'static str {
+        super::Language::JavaScript.id()
+    }
+
+    fn file_extensions(&self) -> &'static [&'static str] {
+        super::Language::JavaScript.extensions()
+    }
+
     fn parse(&self, source: &[u8]) -> Result<tree_sitter::Tree, String> {
         self.parser
             .borrow_mut()
             .parse(source, None)
             .ok_or_else(|| "Failed to parse JavaScript source".to_string())
     }
+
+    fn node_types(&self) -> &NodeTypeMap {
+        &self.node_types
+    }
 }
 
 impl LanguageParser for TsParser {
+    fn language_id(&self) -> &'static str {
+        super::Language::TypeScript.id()
+    }
+
+    fn file_extensions(&self) -> &'static [&'static str] {
+        super::Language::TypeScript.extensions()
+    }
+
     fn parse(&self, source: &[u8]) -> Result<tree_sitter::Tree, String> {
         self.parser
             .borrow_mut()
             .parse(source, None)
             .ok_or_else(|| "Failed to parse TypeScript source".to_string())
     }
+
+    fn node_types(&self) -> &NodeTypeMap {
+        &self.node_types
+    }
 }
 
 impl LanguageParser for TsxParser {
+    fn language_id(&self) -> &'static str {
+        super::Language::Tsx.id()
+    }
+
+    fn file_extensions(&self) -> &'static [&'static str] {
+        super::Language::Tsx.extensions()
+    }
+
     fn parse(&self, source: &[u8]) -> Result<tree_sitter::Tree, String> {
         self.parser
             .borrow_mut()
             .parse(source, None)
             .ok_or_else(|| "Failed to parse TSX source".to_string())
     }
-}
 
-pub fn parser_for_extension(ext: &str) -> Option<Result<Box<dyn LanguageParser>, String>> {
-    match ext {
-        "js" | "jsx" => Some(JsParser::new().map(|p| Box::new(p) as Box<dyn LanguageParser>)),
-        "ts" => Some(TsParser::new().map(|p| Box::new(p) as Box<dyn LanguageParser>)),
-        "tsx" => Some(TsxParser::new().map(|p| Box::new(p) as Box<dyn LanguageParser>)),
-        _ => None,
+    fn node_types(&self) -> &NodeTypeMap {
+        &self.node_types
     }
 }
@@ -3,7 +3,214 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
 pub mod javascript;
+pub mod python;
+pub mod rust;
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
 
 pub trait LanguageParser {
+    /// Stable identifier such as `"javascript"` or `"python"`.
+    fn language_id(&self) -> &'static str;
+    fn file_extensions(&self) -> &'static [&'static str];
     fn parse(&self, source: &[u8]) -> Result<tree_sitter::Tree, String>;
+    /// Grammar node types for each semantic concept rules look for.
+    fn node_types(&self) -> &NodeTypeMap;
+}
+
+/// A grammar-independent node category. Rules match on these rather than on
+/// grammar node names, so the same rule runs on every supported language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SemanticNode {
+    /// Any comment, including doc comments.
+    Comment,
+    /// A name whose words are meaningful for comparing code with prose:
+    /// variables, properties, fields and shorthand properties.
+    Identifier,
+    /// The handler of a try statement: `catch` clauses and `except` clauses.
+    CatchClause,
+    /// A braced or indented statement block.
+    Block,
+    /// A function or method call.
+    CallExpression,
+    /// An anonymous function: arrow functions, function expressions, lambdas
+    /// and closures.
+    Closure,
+    /// A named function or method definition.
+    Function,
+    /// A statement that opens a nested control-flow block: conditionals,
+    /// loops, `switch`/`match` and `try`.
+    ControlFlow,
+    /// The `else` branch of a conditional, whose direct child is the `if` of
+    /// an `else if`.
+    ElseClause,
+    /// A decision point that adds one to cyclomatic complexity: conditionals,
+    /// loops, cases, catch clauses, ternaries and short-circuit operators.
+    Branch,
+    /// A statement that pauses execution in an attached debugger.
+    DebuggerStatement,
+    /// A `return`, with or without a value.
+    Return,
+    /// A statement that raises an exception: `throw` and `raise`.
+    Throw,
+    /// A parameter that collects the remaining arguments: `...args`, `*args`
+    /// and `**kwargs`.
+    RestPattern,
+    /// A pattern that unpacks its value into several names: `{ a, b }`,
+    /// `[a, b]`, `(a, b)`.
+    DestructuringPattern,
+}
+
+impl SemanticNode {
+    pub const ALL: &[SemanticNode] = &[
+        Self::Comment,
+        Self::Identifier,
+        Self::CatchClause,
+        Self::Block,
+        Self::CallExpression,
+        Self::Closure,
+        Self::Function,
+        Self::ControlFlow,
+        Self::ElseClause,
+        Self::Branch,
+        Self::DebuggerStatement,
+        Self::Return,
+        Self::Throw,
+        Self::RestPattern,
+        Self::DestructuringPattern,
+    ];
+}
+
+pub type NodeTypeMap = HashMap<SemanticNode, Vec<&'static str>>;
+
+/// A source language patina can parse. The scanner, rules and `patina fix`
+/// resolve files through this registry rather than matching extensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    JavaScript,
+    TypeScript,
+    Tsx,
+    Python,
+    Rust,
+}
+
+impl Language {
+    pub const ALL: &[Language] = &[
+        Self::JavaScript,
+        Self::TypeScript,
+        Self::Tsx,
+        Self::Python,
+        Self::Rust,
+    ];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::JavaScript => "javascript",
+            Self::TypeScript => "typescript",
+            Self::Tsx => "tsx",
+            Self::Python => "python",
+            Self::Rust => "rust",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|language| language.id() == id)
+    }
+
+    /// The tree-sitter grammar, for compiling queries against it.
+    pub fn grammar(self) -> tree_sitter::Language {
+        match self {
+            Self::JavaScript => tree_sitter_javascript::LANGUAGE.into(),
+            Self::TypeScript => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+            Self::Tsx => tree_sitter_typescript::LANGUAGE_TSX.into(),
+            Self::Python => tree_sitter_python::LANGUAGE.into(),
+            Self::Rust => tree_sitter_rust::LANGUAGE.into(),
+        }
+    }
+
+    pub fn extensions(self) -> &'static [&'static str] {
+        match self {
+            Self::JavaScript => javascript::JS_EXTENSIONS,
+            Self::TypeScript => javascript::TS_EXTENSIONS,
+            Self::Tsx => javascript::TSX_EXTENSIONS,
+            Self::Python => python::EXTENSIONS,
+            Self::Rust => rust::EXTENSIONS,
+        }
+    }
+
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|language| language.extensions().contains(&ext))
+    }
+
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension()
+            .and_then(|e| e.to_str())
+            .and_then(Self::from_extension)
+    }
+
+    /// Creates a fresh parser. Parsers are not thread-safe, so callers keep
+    /// one per thread.
+    pub fn parser(self) -> Result<Box<dyn LanguageParser>, String> {
+        let parser = match self {
+            Self::JavaScript => javascript::JsParser::new().map(|p| Box::new(p) as Box<dyn LanguageParser>),
+            Self::TypeScript => javascript::TsParser::new().map(|p| Box::new(p) as Box<dyn LanguageParser>),
+            Self::Tsx => javascript::TsxParser::new().map(|p| Box::new(p) as Box<dyn LanguageParser>),
+            Self::Python => python::PyParser::new().map(|p| Box::new(p) as Box<dyn LanguageParser>),
+            Self::Rust => rust::RustParser::new().map(|p| Box::new(p) as Box<dyn LanguageParser>),
+        }?;
+        debug_assert_eq!(parser.file_extensions(), self.extensions(), "{} parser", self.id());
+        Ok(parser)
+    }
+}
+
+/// Returns true for API documentation comments: JSDoc `/** */` blocks and
+/// Rust `///`, `//!`, `/** */` and `/*! */` doc comments.
+pub fn is_doc_comment(comment_text: &str) -> bool {
+    let text = comment_text.trim_start();
+    (text.starts_with("///") && !text.starts_with("////"))
+        || text.starts_with("//!")
+        || (text.starts_with("/**") && !text.starts_with("/**/"))
+        || text.starts_with("/*!")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parsers_agree_with_registry() {
+        for &language in Language::ALL {
+            let parser = language.parser().unwrap();
+            assert_eq!(parser.language_id(), language.id());
+            assert_eq!(Language::from_id(language.id()), Some(language));
+            assert_eq!(
+                parser.parse(b"").unwrap().language().abi_version(),
+                language.grammar().abi_version()
+            );
+            assert_eq!(parser.file_extensions(), language.extensions());
+            for ext in parser.file_extensions() {
+                assert_eq!(Language::from_extension(ext), Some(language));
+            }
+            for semantic in SemanticNode::ALL {
+                assert!(parser.node_types().contains_key(semantic), "{language:?} {semantic:?}");
+            }
+            assert_eq!(parser.node_types().len(), SemanticNode::ALL.len(), "{language:?}");
+        }
+    }
+
+    #[test]
+    fn test_doc_comments() {
+        assert!(is_doc_comment("/** Returns the user. */"));
+        assert!(is_doc_comment("/// Returns the user."));
+        assert!(is_doc_comment("//! Account helpers."));
+        assert!(is_doc_comment("/*! Account helpers. */"));
+        assert!(!is_doc_comment("//// Not a doc comment"));
+        assert!(!is_doc_comment("// Plain comment"));
+        assert!(!is_doc_comment("/**/"));
+    }
 }
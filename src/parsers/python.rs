@@ -0,0 +1,138 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use super::{LanguageParser, NodeTypeMap, SemanticNode};
+use std::cell::RefCell;
+use tree_sitter::{Language, Node, Parser};
+
+pub const EXTENSIONS: &[&str] = &["py", "pyi"];
+
+pub struct PyParser {
+    parser: RefCell<Parser>,
+    node_types: NodeTypeMap,
+}
+
+impl PyParser {
+    pub fn new() -> Result<Self, String> {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&Language::from(tree_sitter_python::LANGUAGE))
+            .map_err(|e| format!("Failed to set Python language: {e}"))?;
+        let node_types = NodeTypeMap::from([
+            (SemanticNode::Comment, vec!["comment"]),
+            (SemanticNode::Identifier, vec!["identifier"]),
+            (SemanticNode::CatchClause, vec!["except_clause"]),
+            (SemanticNode::Block, vec!["block"]),
+            (SemanticNode::CallExpression, vec!["call"]),
+            (SemanticNode::Closure, vec!["lambda"]),
+            (SemanticNode::Function, vec!["function_definition"]),
+            (
+                SemanticNode::ControlFlow,
+                vec!["if_statement", "for_statement", "while_statement", "try_statement", "match_statement"],
+            ),
+            (SemanticNode::ElseClause, vec!["else_clause"]),
+            (
+                SemanticNode::Branch,
+                vec![
+                    "if_statement",
+                    "elif_clause",
+                    "for_statement",
+                    "while_statement",
+                    "except_clause",
+                    "case_clause",
+                    "conditional_expression",
+                    "and",
+                    "or",
+                ],
+            ),
+            // `breakpoint()` is an ordinary call
+            (SemanticNode::DebuggerStatement, vec![]),
+            (SemanticNode::Return, vec!["return_statement"]),
+            (SemanticNode::Throw, vec!["raise_statement"]),
+            (SemanticNode::RestPattern, vec!["list_splat_pattern", "dictionary_splat_pattern"]),
+            (SemanticNode::DestructuringPattern, vec!["tuple_pattern", "list_pattern"]),
+        ]);
+        Ok(Self { parser: RefCell::new(parser), node_types })
+    }
+}
+
+impl LanguageParser for PyParser {
+    fn language_id(&self) -> &'static str {
+        super::Language::Python.id()
+    }
+
+    fn file_extensions(&self) -> &'static [&'static str] {
+        super::Language::Python.extensions()
+    }
+
+    fn parse(&self, source: &[u8]) -> Result<tree_sitter::Tree, String> {
+        self.parser
+            .borrow_mut()
+            .parse(source, None)
+            .ok_or_else(|| "Failed to parse Python source".to_string())
+    }
+
+    fn node_types(&self) -> &NodeTypeMap {
+        &self.node_types
+    }
+}
+
+/// Returns true if `node` is a docstring: a string literal that is the first
+/// statement of a module, class or function body.
+pub fn is_docstring(node: Node) -> bool {
+    if node.kind() != "string" {
+        return false;
+    }
+    let Some(statement) = node.parent().filter(|p| p.kind() == "expression_statement") else {
+        return false;
+    };
+    let Some(body) = statement.parent() else {
+        return false;
+    };
+    let owner_allows_docstring = match body.kind() {
+        "module" => true,
+        "block" => body
+            .parent()
+            .is_some_and(|p| matches!(p.kind(), "function_definition" | "class_definition")),
+        _ => false,
+    };
+
+    let mut cursor = body.walk();
+    let first_statement = body
+        .named_children(&mut cursor)
+        .find(|child| child.kind() != "comment");
+    owner_allows_docstring && first_statement == Some(statement)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn docstrings(source: &str) -> Vec<String> {
+        let tree = PyParser::new().unwrap().parse(source.as_bytes()).unwrap();
+        let mut found = Vec::new();
+        let mut stack = vec![tree.root_node()];
+        while let Some(node) = stack.pop() {
+            if is_docstring(node) {
+                found.push(node.utf8_text(source.as_bytes()).unwrap().to_string());
+            }
+            let mut cursor = node.walk();
+            stack.extend(node.children(&mut cursor));
+        }
+        found.sort();
+        found
+    }
+
+    #[test]
+    fn test_docstrings_are_first_statements_of_bodies() {
+        let source = "\"\"\"Module.\"\"\"\n\n\
+            def f():\n    # leading comment\n    \"\"\"Function.\"\"\"\n    \"not a docstring\"\n\n\
+            class C:\n    \"\"\"Class.\"\"\"\n\n\
+            if x:\n    \"not a docstring either\"\n";
+        assert_eq!(
+            docstrings(source),
+            vec!["\"\"\"Class.\"\"\"", "\"\"\"Function.\"\"\"", "\"\"\"Module.\"\"\""]
+        );
+    }
+}
@@ -0,0 +1,72 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use super::{LanguageParser, NodeTypeMap, SemanticNode};
+use std::cell::RefCell;
+use tree_sitter::{Language, Parser};
+
+pub const EXTENSIONS: &[&str] = &["rs"];
+
+pub struct RustParser {
+    parser: RefCell<Parser>,
+    node_types: NodeTypeMap,
+}
+
+impl RustParser {
+    pub fn new() -> Result<Self, String> {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&Language::from(tree_sitter_rust::LANGUAGE))
+            .map_err(|e| format!("Failed to set Rust language: {e}"))?;
+        let node_types = NodeTypeMap::from([
+            (SemanticNode::Comment, vec!["line_comment", "block_comment"]),
+            (SemanticNode::Identifier, vec!["identifier", "field_identifier"]),
+            // Rust reports errors through `Result`, not exceptions
+            (SemanticNode::CatchClause, vec![]),
+            (SemanticNode::Block, vec!["block"]),
+            (SemanticNode::CallExpression, vec!["call_expression"]),
+            (SemanticNode::Closure, vec!["closure_expression"]),
+            (SemanticNode::Function, vec!["function_item"]),
+            (
+                SemanticNode::ControlFlow,
+                vec!["if_expression", "for_expression", "while_expression", "loop_expression", "match_expression"],
+            ),
+            (SemanticNode::ElseClause, vec!["else_clause"]),
+            (
+                SemanticNode::Branch,
+                vec!["if_expression", "for_expression", "while_expression", "match_arm", "&&", "||"],
+            ),
+            (SemanticNode::DebuggerStatement, vec![]),
+            (SemanticNode::Return, vec!["return_expression"]),
+            (SemanticNode::Throw, vec![]),
+            (SemanticNode::RestPattern, vec![]),
+            (
+                SemanticNode::DestructuringPattern,
+                vec!["tuple_pattern", "struct_pattern", "tuple_struct_pattern", "slice_pattern"],
+            ),
+        ]);
+        Ok(Self { parser: RefCell::new(parser), node_types })
+    }
+}
+
+impl LanguageParser for RustParser {
+    fn language_id(&self) -> &'static str {
+        super::Language::Rust.id()
+    }
+
+    fn file_extensions(&self) -> &'static [&'static str] {
+        super::Language::Rust.extensions()
+    }
+
+    fn parse(&self, source: &[u8]) -> Result<tree_sitter::Tree, String> {
+        self.parser
+            .borrow_mut()
+            .parse(source, None)
+            .ok_or_else(|| "Failed to parse Rust source".to_string())
+    }
+
+    fn node_types(&self) -> &NodeTypeMap {
+        &self.node_types
+    }
+}
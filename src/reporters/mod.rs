@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
 pub mod json;
+pub mod sarif;
 pub mod terminal;
 
 use crate::types::Finding;
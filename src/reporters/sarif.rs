@@ -0,0 +1,203 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::baseline::{fingerprint, normalize_path};
+use crate::reporters::Reporter;
+use crate::rules::Rule;
+use crate::suppression::UNUSED_SUPPRESSION_ID;
+use crate::types::{Finding, Severity};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Emits a SARIF 2.1.0 log for GitHub code scanning and other SARIF consumers.
+pub struct SarifReporter {
+    descriptors: Vec<(&'static str, Value)>,
+}
+
+impl SarifReporter {
+    /// A reporter describing `rules`, the project's built-in and custom rules.
+    pub fn new(rules: &[Box<dyn Rule>]) -> Self {
+        Self {
+            descriptors: rule_descriptors(rules),
+        }
+    }
+}
+
+impl Reporter for SarifReporter {
+    fn report(&self, findings: &[Finding], sources: &HashMap<PathBuf, Vec<u8>>) -> Result<(), Box<dyn std::error::Error>> {
+        let log = sarif_log(findings, sources, &self.descriptors);
+        println!("{}", serde_json::to_string_pretty(&log)?);
+        Ok(())
+    }
+}
+
+/// SARIF `reportingDescriptor`s for `rules` plus the `unused-suppression`
+/// pseudo-rule, paired with their IDs.
+pub fn rule_descriptors(rules: &[Box<dyn Rule>]) -> Vec<(&'static str, Value)> {
+    let mut descriptors: Vec<(&'static str, Value)> = rules
+        .iter()
+        .map(|rule| {
+            let descriptor =
+                rule_descriptor(rule.id(), rule.name(), rule.description(), rule.severity());
+            (rule.id(), descriptor)
+        })
+        .collect();
+    descriptors.push((
+        UNUSED_SUPPRESSION_ID,
+        rule_descriptor(
+            UNUSED_SUPPRESSION_ID,
+            "Unused Suppression",
+            "Reports patina suppression comments that no longer match any finding",
+            Severity::Warn,
+        ),
+    ));
+    descriptors
+}
+
+fn sarif_log(
+    findings: &[Finding],
+    sources: &HashMap<PathBuf, Vec<u8>>,
+    descriptors: &[(&'static str, Value)],
+) -> Value {
+    let (rule_ids, descriptors): (Vec<&str>, Vec<Value>) = descriptors.iter().cloned().unzip();
+
+    let results: Vec<Value> = findings
+        .iter()
+        .map(|finding| {
+            let source = sources.get(&finding.file).map_or(&[][..], Vec::as_slice);
+            let mut result = json!({
+                "ruleId": finding.rule_id,
+                "level": level(finding.severity),
+                "message": { "text": finding.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": artifact_location(&finding.file),
+                        "region": region(&finding.span, source),
+                    }
+                }],
+                "partialFingerprints": {
+                    "patinaFingerprint/v1": fingerprint(finding, source),
+                },
+            });
+            if let Some(index) = rule_ids.iter().position(|id| *id == finding.rule_id) {
+                result["ruleIndex"] = json!(index);
+            }
+            if !finding.related.is_empty() {
+                let related: Vec<Value> = finding
+                    .related
+                    .iter()
+                    .enumerate()
+                    .map(|(id, location)| {
+                        let source = sources.get(&location.file).map_or(&[][..], Vec::as_slice);
+                        json!({
+                            "id": id,
+                            "message": { "text": location.message },
+                            "physicalLocation": {
+                                "artifactLocation": artifact_location(&location.file),
+                                "region": region(&location.span, source),
+                            }
+                        })
+                    })
+                    .collect();
+                result["relatedLocations"] = json!(related);
+            }
+            if let Some(metrics) = &finding.metrics {
+                result["properties"] = json!({ "metrics": metrics });
+            }
+            if let (Some(suggestion), Some(fix)) = (&finding.suggestion, &finding.fix) {
+                let mut replacement = json!({
+                    "deletedRegion": {
+                        "byteOffset": fix.span.start,
+                        "byteLength": fix.span.len(),
+                    }
+                });
+                if !fix.replacement.is_empty() {
+                    replacement["insertedContent"] = json!({ "text": fix.replacement });
+                }
+                result["fixes"] = json!([{
+                    "description": { "text": suggestion },
+                    "artifactChanges": [{
+                        "artifactLocation": artifact_location(&finding.file),
+                        "replacements": [replacement]
+                    }]
+                }]);
+            }
+            result
+        })
+        .collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "patina",
+                    "informationUri": "https://github.com/patina-dev/patina",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": descriptors,
+                }
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }]
+    })
+}
+
+fn rule_descriptor(id: &str, name: &str, description: &str, severity: Severity) -> Value {
+    // SARIF rule names are identifiers, e.g. "Filler/Hedge Words" -> "FillerHedgeWords"
+    let name: String = name.chars().filter(|c| c.is_alphanumeric()).collect();
+    json!({
+        "id": id,
+        "name": name,
+        "shortDescription": { "text": description },
+        "defaultConfiguration": { "level": level(severity) },
+    })
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warn => "warning",
+        Severity::Info => "note",
+    }
+}
+
+/// Relative paths are resolved against `%SRCROOT%` by the consumer; absolute
+/// paths become `file://` URIs.
+fn artifact_location(path: &Path) -> Value {
+    let uri = normalize_path(path);
+    if path.is_absolute() {
+        json!({ "uri": format!("file://{uri}") })
+    } else {
+        json!({ "uri": uri, "uriBaseId": "%SRCROOT%" })
+    }
+}
+
+/// Builds a region with 1-based line/column (in code points, matching
+/// `columnKind`) plus the exact byte range of `span`.
+fn region(span: &Range<usize>, source: &[u8]) -> Value {
+    let (start_line, start_column) = line_column(source, span.start);
+    let (end_line, end_column) = line_column(source, span.end);
+    json!({
+        "startLine": start_line,
+        "startColumn": start_column,
+        "endLine": end_line,
+        "endColumn": end_column,
+        "byteOffset": span.start,
+        "byteLength": span.len(),
+    })
+}
+
+fn line_column(source: &[u8], offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line_start = before.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+    let line = before.iter().filter(|&&b| b == b'\n').count() + 1;
+    let column = String::from_utf8_lossy(&before[line_start..]).chars().count() + 1;
+    (line, column)
+}
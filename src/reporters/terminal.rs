@@ -42,15 +42,35 @@ impl Reporter for TerminalReporter {
                     .unwrap_or("comment restates the adjacent code");
 
                 let report =
-                    Report::build(kind, (file_id.as_str(), finding.span.clone()))
+                    Report::build(kind, (file_id.clone(), finding.span.clone()))
                         .with_message(format!("[{}] {}", finding.rule_id, finding.message))
                         .with_label(
-                            Label::new((file_id.as_str(), finding.span.clone()))
+                            Label::new((file_id.clone(), finding.span.clone()))
                                 .with_message(label_msg),
                         )
+                        .with_labels(finding.related.iter().filter(|related| {
+                            !(related.file == **file_path && related.span == finding.span)
+                        }).map(|related| {
+                            Label::new((related.file.display().to_string(), related.span.clone()))
+                                .with_message(&related.message)
+                        }))
                         .finish();
 
-                report.eprint((file_id.as_str(), &source))?;
+                if finding.related.is_empty() {
+                    report.eprint((file_id.clone(), &source))?;
+                } else {
+                    // Related locations may point into other files
+                    let mut files = vec![(file_id.clone(), source_text.to_string())];
+                    for related in &finding.related {
+                        let id = related.file.display().to_string();
+                        if !files.iter().any(|(file, _)| *file == id)
+                            && let Some(bytes) = sources.get(&related.file)
+                        {
+                            files.push((id, String::from_utf8_lossy(bytes).into_owned()));
+                        }
+                    }
+                    report.eprint(ariadne::sources(files))?;
+                }
             }
         }
 
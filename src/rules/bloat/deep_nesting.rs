@@ -0,0 +1,190 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::parsers::SemanticNode;
+use crate::rules::{Rule, RuleContext, RuleDocs, RuleOption, parse_options};
+use crate::types::{Category, Finding, Severity};
+use serde::Deserialize;
+
+const MAX_DEPTH: usize = 4;
+
+pub struct DeepNesting {
+    max_depth: usize,
+}
+
+impl Default for DeepNesting {
+    fn default() -> Self {
+        Self {
+            max_depth: MAX_DEPTH,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Options {
+    max_depth: Option<usize>,
+}
+
+impl Rule for DeepNesting {
+    fn id(&self) -> &'static str {
+        "bloat-001"
+    }
+    fn name(&self) -> &'static str {
+        "Deep Nesting"
+    }
+    fn description(&self) -> &'static str {
+        "Detects functions whose control flow nests deeper than a configurable limit"
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warn
+    }
+    fn category(&self) -> Category {
+        Category::Bloat
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: "Every level of nesting is another condition the reader has to hold in \
+                their head. Code five levels deep is usually missing guard clauses, early returns \
+                or a helper function, and it is where bugs in the unhappy paths hide.",
+            heuristic: "Inside each function, every `if`, loop, `switch`/`match`, `try` and nested \
+                callback or function adds one level; an `else if` stays at the level of its `if`. \
+                The block that takes a function past `max_depth` (4) levels is flagged once, and \
+                the message shows the path to the deepest block beneath it.",
+            flagged_example: "function notify(users) {\n  for (const user of users) {\n    if (user.active) {\n      for (const device of user.devices) {\n        if (device.enabled) {\n          if (device.token) {\n            push(device.token);\n          }\n        }\n      }\n    }\n  }\n}",
+            not_flagged_example: "function notify(users) {\n  for (const user of users) {\n    if (!user.active) continue;\n    for (const device of user.devices) {\n      if (device.enabled && device.token) {\n        push(device.token);\n      }\n    }\n  }\n}",
+            options: &[RuleOption {
+                name: "max_depth",
+                default: "4",
+                description: "Deepest nesting allowed inside a function",
+            }],
+            false_positives: &[
+                "Test suites that nest `describe`/`it` callbacks several levels deep",
+                "Promise chains written with nested callbacks for a legacy API",
+            ],
+        }
+    }
+
+    fn configure(&mut self, config: &toml::Value) -> Result<(), String> {
+        let options: Options = parse_options(config)?;
+        if let Some(max_depth) = options.max_depth {
+            if max_depth == 0 {
+                return Err("max_depth must be at least 1".to_string());
+            }
+            self.max_depth = max_depth;
+        }
+        Ok(())
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        self.visit(ctx, ctx.tree.root_node(), &mut Vec::new(), false, &mut findings);
+        findings
+    }
+}
+
+impl DeepNesting {
+    /// Walks `node`'s children, keeping `path` as the nesting blocks between
+    /// the enclosing function and the current node.
+    fn visit<'t>(
+        &self,
+        ctx: &RuleContext,
+        node: tree_sitter::Node<'t>,
+        path: &mut Vec<tree_sitter::Node<'t>>,
+        in_function: bool,
+        findings: &mut Vec<Finding>,
+    ) {
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            let is_function =
+                ctx.is(child, SemanticNode::Function) || ctx.is(child, SemanticNode::Closure);
+            if !in_function {
+                // Code outside any function has no depth; the first function starts at 0
+                self.visit(ctx, child, path, is_function, findings);
+                continue;
+            }
+            if !is_function && !Self::is_nesting_block(ctx, child) {
+                self.visit(ctx, child, path, true, findings);
+                continue;
+            }
+
+            path.push(child);
+            if path.len() == self.max_depth + 1 {
+                findings.push(self.finding(ctx, child, path));
+            }
+            self.visit(ctx, child, path, true, findings);
+            path.pop();
+        }
+    }
+
+    /// Control flow that opens a level. The `if` of an `else if` continues its
+    /// parent's chain instead.
+    fn is_nesting_block(ctx: &RuleContext, node: tree_sitter::Node) -> bool {
+        ctx.is(node, SemanticNode::ControlFlow)
+            && !node.parent().is_some_and(|parent| ctx.is(parent, SemanticNode::ElseClause))
+    }
+
+    /// The longest chain of nesting blocks strictly inside `node`.
+    fn deepest_chain<'t>(ctx: &RuleContext, node: tree_sitter::Node<'t>) -> Vec<tree_sitter::Node<'t>> {
+        let mut deepest = Vec::new();
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            let mut chain = Self::deepest_chain(ctx, child);
+            if Self::is_nesting_block(ctx, child)
+                || ctx.is(child, SemanticNode::Function)
+                || ctx.is(child, SemanticNode::Closure)
+            {
+                chain.insert(0, child);
+            }
+            if chain.len() > deepest.len() {
+                deepest = chain;
+            }
+        }
+        deepest
+    }
+
+    fn finding(&self, ctx: &RuleContext, node: tree_sitter::Node, path: &[tree_sitter::Node]) -> Finding {
+        let mut chain = path.to_vec();
+        chain.extend(Self::deepest_chain(ctx, node));
+        let labels: Vec<&str> = chain.iter().map(|n| Self::label(ctx, *n)).collect();
+
+        let start = node.start_position();
+        Finding {
+            rule_id: "",
+            message: format!(
+                "nesting depth {} exceeds the limit of {} ({})",
+                chain.len(),
+                self.max_depth,
+                labels.join(" > ")
+            ),
+            severity: Severity::Warn,
+            file: ctx.file_path.to_path_buf(),
+            line: start.row + 1,
+            column: start.column + 1,
+            span: node.byte_range(),
+            suggestion: Some(
+                "Flatten with guard clauses or early returns, or extract the inner block into a function."
+                    .to_string(),
+            ),
+            fix: None,
+            metrics: None,
+            related: Vec::new(),
+        }
+    }
+
+    /// A short name for a nesting level: the statement's keyword (`if`, `for`,
+    /// `try`, ...), `callback` or `function`.
+    fn label<'s>(ctx: &RuleContext<'s>, node: tree_sitter::Node) -> &'s str {
+        if ctx.is(node, SemanticNode::Closure) {
+            return "callback";
+        }
+        if ctx.is(node, SemanticNode::Function) {
+            return "function";
+        }
+        node.child(0)
+            .filter(|keyword| !keyword.is_named())
+            .and_then(|keyword| keyword.utf8_text(ctx.source).ok())
+            .unwrap_or("block")
+    }
+}
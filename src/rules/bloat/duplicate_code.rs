@@ -0,0 +1,312 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::rules::code_tokens::{self, CodeToken};
+use crate::rules::{DynProjectRule, ProjectFile, ProjectRule, Rule, RuleContext, RuleDocs, RuleOption, parse_options};
+use crate::types::{Category, Finding, RelatedLocation, Severity};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::ops::Range;
+
+const MIN_TOKENS: usize = 60;
+
+/// Length of the token runs that are hashed and compared.
+const GRAM_LENGTH: usize = 12;
+
+/// Hashes seen more often than this are boilerplate, and comparing every pair
+/// of occurrences would be quadratic.
+const MAX_OCCURRENCES: usize = 32;
+
+pub struct DuplicateCode {
+    min_tokens: usize,
+}
+
+impl Default for DuplicateCode {
+    fn default() -> Self {
+        Self {
+            min_tokens: MIN_TOKENS,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Options {
+    min_tokens: Option<usize>,
+}
+
+type TokenFile = ProjectFile<Vec<CodeToken>>;
+
+/// The same token run at `a` in file `files.0` and at `b` in file `files.1`.
+struct ClonePair {
+    files: (usize, usize),
+    a: Range<usize>,
+    b: Range<usize>,
+}
+
+impl Rule for DuplicateCode {
+    fn id(&self) -> &'static str {
+        "bloat-003"
+    }
+    fn name(&self) -> &'static str {
+        "Duplicate Code"
+    }
+    fn description(&self) -> &'static str {
+        "Detects code duplicated across files, ignoring names and literal values"
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warn
+    }
+    fn category(&self) -> Category {
+        Category::Bloat
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: "Generated code is often pasted into each module that needs it instead of \
+                being imported from one place. Every copy then needs the same bug fix, and the \
+                copies drift apart as some get it and others do not.",
+            heuristic: "After every file has been analyzed, the code inside functions is reduced \
+                to its tokens, with names and literal values replaced by placeholders so renamed \
+                copies still match. Runs of 12 tokens are hashed (Rabin-Karp) and a winnowed \
+                subset of the hashes is indexed. Each hash shared by two files seeds a match that \
+                is extended in both directions, and matches of at least `min_tokens` tokens are \
+                reported in the later file, with a related location pointing at each earlier \
+                copy. Duplicates within one file are left to uniform-001.",
+            flagged_example: "// file: users.js\nexport async function loadUsers(page) {\n  const response = await fetch(`/api/users?page=${page}`);\n  if (!response.ok) {\n    throw new Error(`Request failed: ${response.status}`);\n  }\n  const body = await response.json();\n  return body.items.filter((item) => !item.deleted);\n}\n// file: orders.js\nexport async function loadOrders(page) {\n  const response = await fetch(`/api/orders?page=${page}`);\n  if (!response.ok) {\n    throw new Error(`Request failed: ${response.status}`);\n  }\n  const body = await response.json();\n  return body.items.filter((item) => !item.deleted);\n}",
+            not_flagged_example: "// file: api.js\nexport async function loadPage(resource, page) {\n  const response = await fetch(`/api/${resource}?page=${page}`);\n  if (!response.ok) {\n    throw new Error(`Request failed: ${response.status}`);\n  }\n  const body = await response.json();\n  return body.items.filter((item) => !item.deleted);\n}\n// file: orders.js\nexport const loadOrders = (page) => loadPage(\"orders\", page);",
+            options: &[RuleOption {
+                name: "min_tokens",
+                default: "60",
+                description: "Shortest duplicated run reported, in tokens (at least 12)",
+            }],
+            false_positives: &[
+                "Generated files such as API clients, which are duplicated by design",
+                "Short functions with a common shape, when `min_tokens` is set low",
+            ],
+        }
+    }
+
+    fn configure(&mut self, config: &toml::Value) -> Result<(), String> {
+        let options: Options = parse_options(config)?;
+        if let Some(min_tokens) = options.min_tokens {
+            if min_tokens < GRAM_LENGTH {
+                return Err(format!("min_tokens must be at least {GRAM_LENGTH}"));
+            }
+            self.min_tokens = min_tokens;
+        }
+        Ok(())
+    }
+
+    fn check(&self, _ctx: &RuleContext) -> Vec<Finding> {
+        // Copies within one file are uniform-001's; this rule only compares files
+        Vec::new()
+    }
+
+    fn project(&self) -> Option<&dyn DynProjectRule> {
+        Some(self)
+    }
+}
+
+impl ProjectRule for DuplicateCode {
+    type Summary = Vec<CodeToken>;
+
+    fn collect(&self, ctx: &RuleContext) -> Option<Vec<CodeToken>> {
+        let tokens = code_tokens::function_tokens(ctx);
+        (tokens.len() >= self.min_tokens).then_some(tokens)
+    }
+
+    fn finalize(&self, files: &[TokenFile]) -> Vec<Finding> {
+        let mut index: HashMap<u64, Vec<(usize, usize)>> = HashMap::new();
+        for (file, project_file) in files.iter().enumerate() {
+            for (hash, position) in self.winnow(&project_file.summary) {
+                index.entry(hash).or_default().push((file, position));
+            }
+        }
+
+        let mut clones: Vec<ClonePair> = Vec::new();
+        for occurrences in index.values() {
+            if occurrences.len() > MAX_OCCURRENCES {
+                continue;
+            }
+            for (i, &(file_a, at_a)) in occurrences.iter().enumerate() {
+                for &(file_b, at_b) in &occurrences[i + 1..] {
+                    let (a, b) = if file_a < file_b {
+                        ((file_a, at_a), (file_b, at_b))
+                    } else {
+                        ((file_b, at_b), (file_a, at_a))
+                    };
+                    if a.0 == b.0 || is_covered(&clones, a, b) {
+                        continue;
+                    }
+                    if let Some(clone) = extend(files, a, b)
+                        && clone.a.len() >= self.min_tokens
+                    {
+                        clones.push(clone);
+                    }
+                }
+            }
+        }
+
+        // Report each run once in the later file, listing every earlier copy
+        clones.sort_by_key(|clone| (clone.files.1, clone.b.start, clone.b.end, clone.files.0, clone.a.start));
+        let mut findings: Vec<Finding> = Vec::new();
+        let mut reported: Option<(usize, Range<usize>)> = None;
+        for clone in &clones {
+            let (file_a, file_b) = clone.files;
+            let copy = related(&files[file_a], &clone.a);
+            if reported.as_ref() == Some(&(file_b, clone.b.clone()))
+                && let Some(finding) = findings.last_mut()
+            {
+                finding.related.push(copy);
+                continue;
+            }
+            findings.push(finding(&files[file_b], &clone.b, &files[file_a], &clone.a, copy));
+            reported = Some((file_b, clone.b.clone()));
+        }
+        findings
+    }
+}
+
+impl DuplicateCode {
+    /// Selects the smallest hash in every window of hashes, so any shared run
+    /// of `min_tokens` tokens has at least one selected hash in common.
+    fn winnow(&self, tokens: &[CodeToken]) -> Vec<(u64, usize)> {
+        let hashes = gram_hashes(tokens);
+        let window = self.min_tokens - GRAM_LENGTH + 1;
+        let mut selected: Vec<(u64, usize)> = Vec::new();
+        if hashes.is_empty() {
+            return selected;
+        }
+        for start in 0..hashes.len().saturating_sub(window - 1).max(1) {
+            let end = (start + window).min(hashes.len());
+            let (position, &hash) = hashes[start..end]
+                .iter()
+                .enumerate()
+                .rev()
+                .min_by_key(|(_, hash)| **hash)
+                .map(|(offset, hash)| (start + offset, hash))
+                .expect("window is never empty");
+            if selected.last().is_none_or(|&(_, last)| last != position) {
+                selected.push((hash, position));
+            }
+        }
+        selected
+    }
+}
+
+/// Rabin-Karp rolling hashes of every run of `GRAM_LENGTH` tokens.
+fn gram_hashes(tokens: &[CodeToken]) -> Vec<u64> {
+    const BASE: u64 = 1_000_003;
+    if tokens.len() < GRAM_LENGTH {
+        return Vec::new();
+    }
+    let leading = (1..GRAM_LENGTH).fold(1u64, |power, _| power.wrapping_mul(BASE));
+    let mut hash = tokens[..GRAM_LENGTH]
+        .iter()
+        .fold(0u64, |hash, token| hash.wrapping_mul(BASE).wrapping_add(token.hash));
+    let mut hashes = vec![hash];
+    for i in GRAM_LENGTH..tokens.len() {
+        hash = hash
+            .wrapping_sub(tokens[i - GRAM_LENGTH].hash.wrapping_mul(leading))
+            .wrapping_mul(BASE)
+            .wrapping_add(tokens[i].hash);
+        hashes.push(hash);
+    }
+    hashes
+}
+
+/// True if an earlier clone between the same files already contains both
+/// positions at the same offset.
+fn is_covered(clones: &[ClonePair], a: (usize, usize), b: (usize, usize)) -> bool {
+    clones.iter().any(|clone| {
+        clone.files == (a.0, b.0)
+            && clone.a.contains(&a.1)
+            && clone.b.contains(&b.1)
+            && a.1 - clone.a.start == b.1 - clone.b.start
+    })
+}
+
+/// Grows a seed match in both directions for as long as the tokens agree.
+fn extend(files: &[TokenFile], a: (usize, usize), b: (usize, usize)) -> Option<ClonePair> {
+    let (tokens_a, tokens_b) = (&files[a.0].summary, &files[b.0].summary);
+    let same = |i: usize, j: usize| tokens_a[i].hash == tokens_b[j].hash;
+    if !(0..GRAM_LENGTH).all(|k| same(a.1 + k, b.1 + k)) {
+        // A hash collision
+        return None;
+    }
+    let mut before = 0;
+    while before < a.1 && before < b.1 && same(a.1 - before - 1, b.1 - before - 1) {
+        before += 1;
+    }
+    let mut after = GRAM_LENGTH;
+    while a.1 + after < tokens_a.len() && b.1 + after < tokens_b.len() && same(a.1 + after, b.1 + after) {
+        after += 1;
+    }
+    let mut a_range = a.1 - before..a.1 + after;
+    let mut b_range = b.1 - before..b.1 + after;
+
+    // Function boundaries are zero-width; trim them from the ends
+    while !a_range.is_empty() && tokens_a[a_range.start].span.is_empty() {
+        a_range.start += 1;
+        b_range.start += 1;
+    }
+    while !a_range.is_empty() && tokens_a[a_range.end - 1].span.is_empty() {
+        a_range.end -= 1;
+        b_range.end -= 1;
+    }
+    Some(ClonePair {
+        files: (a.0, b.0),
+        a: a_range,
+        b: b_range,
+    })
+}
+
+/// The byte span and 1-based start of a token run.
+fn location(file: &TokenFile, tokens: &Range<usize>) -> (Range<usize>, usize, usize, usize) {
+    let first = &file.summary[tokens.start];
+    let last = &file.summary[tokens.end - 1];
+    (first.span.start..last.span.end, first.row + 1, first.column + 1, last.row + 1)
+}
+
+fn related(file: &TokenFile, tokens: &Range<usize>) -> RelatedLocation {
+    let (span, line, column, end_line) = location(file, tokens);
+    RelatedLocation {
+        file: file.path.clone(),
+        line,
+        column,
+        span,
+        message: format!("copy at {}:{line}-{end_line}", file.path.display()),
+    }
+}
+
+fn finding(
+    file: &TokenFile,
+    tokens: &Range<usize>,
+    copy_file: &TokenFile,
+    copy_tokens: &Range<usize>,
+    copy: RelatedLocation,
+) -> Finding {
+    let (span, line, column, end_line) = location(file, tokens);
+    let (_, copy_line, _, copy_end_line) = location(copy_file, copy_tokens);
+    Finding {
+        rule_id: "",
+        message: format!(
+            "{} lines ({} tokens) duplicate {}:{copy_line}-{copy_end_line}",
+            end_line - line + 1,
+            tokens.len(),
+            copy_file.path.display(),
+        ),
+        severity: Severity::Warn,
+        file: file.path.clone(),
+        line,
+        column,
+        span,
+        suggestion: Some(
+            "Move the shared code into one module and import it where it is needed.".to_string(),
+        ),
+        fix: None,
+        metrics: None,
+        related: vec![copy],
+    }
+}
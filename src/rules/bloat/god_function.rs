@@ -0,0 +1,274 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::parsers::SemanticNode;
+use crate::rules::{Rule, RuleContext, RuleDocs, RuleOption, parse_options};
+use crate::types::{Category, Finding, FunctionMetrics, Severity};
+use serde::Deserialize;
+
+const MAX_LINES: usize = 100;
+const MAX_STATEMENTS: usize = 50;
+const MAX_COMPLEXITY: usize = 15;
+const MAX_PARAMETERS: usize = 5;
+
+pub struct GodFunction {
+    max_lines: usize,
+    max_statements: usize,
+    max_complexity: usize,
+    max_parameters: usize,
+}
+
+impl Default for GodFunction {
+    fn default() -> Self {
+        Self {
+            max_lines: MAX_LINES,
+            max_statements: MAX_STATEMENTS,
+            max_complexity: MAX_COMPLEXITY,
+            max_parameters: MAX_PARAMETERS,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Options {
+    max_lines: Option<usize>,
+    max_statements: Option<usize>,
+    max_complexity: Option<usize>,
+    max_parameters: Option<usize>,
+}
+
+impl Rule for GodFunction {
+    fn id(&self) -> &'static str {
+        "bloat-002"
+    }
+    fn name(&self) -> &'static str {
+        "God Function"
+    }
+    fn description(&self) -> &'static str {
+        "Detects functions that exceed limits on length, statements, complexity or parameters"
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warn
+    }
+    fn category(&self) -> Category {
+        Category::Bloat
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: "A function that runs to hundreds of lines with dozens of branches does \
+                too many things to name, test or review. Generated handlers tend to grow this \
+                way: every case is inlined rather than given its own function.",
+            heuristic: "Every function, method, arrow function and closure is measured: lines from \
+                its first to its last line, statements in its body, cyclomatic complexity (1 plus \
+                one per `if`, loop, case, catch, ternary and `&&`/`||`/`??`) and parameters \
+                (`self` excluded). Statements and complexity stop at nested functions, which are \
+                measured on their own. A function over any limit is flagged once, naming the \
+                function or the variable it is assigned to. JSON and SARIF output carry all four \
+                metrics.",
+            flagged_example: "function route(method, path, body, user, session, flags) {\n  return dispatch(method, path, body, user, session, flags);\n}",
+            not_flagged_example: "function route(request) {\n  return dispatch(request);\n}",
+            options: &[
+                RuleOption {
+                    name: "max_lines",
+                    default: "100",
+                    description: "Longest function allowed, in lines",
+                },
+                RuleOption {
+                    name: "max_statements",
+                    default: "50",
+                    description: "Most statements allowed in one function body",
+                },
+                RuleOption {
+                    name: "max_complexity",
+                    default: "15",
+                    description: "Highest cyclomatic complexity allowed",
+                },
+                RuleOption {
+                    name: "max_parameters",
+                    default: "5",
+                    description: "Most parameters allowed",
+                },
+            ],
+            false_positives: &[
+                "Table-driven code such as long `switch` statements mapping one value to another",
+                "Generated code, migrations and test fixtures that are long by nature",
+            ],
+        }
+    }
+
+    fn configure(&mut self, config: &toml::Value) -> Result<(), String> {
+        let options: Options = parse_options(config)?;
+        for (name, value, limit) in [
+            ("max_lines", options.max_lines, &mut self.max_lines),
+            ("max_statements", options.max_statements, &mut self.max_statements),
+            ("max_complexity", options.max_complexity, &mut self.max_complexity),
+            ("max_parameters", options.max_parameters, &mut self.max_parameters),
+        ] {
+            if let Some(value) = value {
+                if value == 0 {
+                    return Err(format!("{name} must be at least 1"));
+                }
+                *limit = value;
+            }
+        }
+        Ok(())
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        let mut cursor = ctx.tree.walk();
+        self.walk_tree(ctx, &mut cursor, &mut findings);
+        findings
+    }
+}
+
+impl GodFunction {
+    fn walk_tree(&self, ctx: &RuleContext, cursor: &mut tree_sitter::TreeCursor, findings: &mut Vec<Finding>) {
+        loop {
+            let node = cursor.node();
+
+            if is_function(ctx, node)
+                && let Some(finding) = self.check_function(ctx, node)
+            {
+                findings.push(finding);
+            }
+
+            if cursor.goto_first_child() {
+                continue;
+            }
+            if cursor.goto_next_sibling() {
+                continue;
+            }
+            loop {
+                if !cursor.goto_parent() {
+                    return;
+                }
+                if cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn check_function(&self, ctx: &RuleContext, node: tree_sitter::Node) -> Option<Finding> {
+        let metrics = measure(ctx, node);
+        let exceeded: Vec<String> = [
+            (metrics.lines, self.max_lines, "lines"),
+            (metrics.statements, self.max_statements, "statements"),
+            (metrics.complexity, self.max_complexity, "complexity"),
+            (metrics.parameters, self.max_parameters, "parameters"),
+        ]
+        .into_iter()
+        .filter(|(value, limit, _)| value > limit)
+        .map(|(value, limit, metric)| format!("{metric} {value} (max {limit})"))
+        .collect();
+        if exceeded.is_empty() {
+            return None;
+        }
+
+        let subject = match ctx.function_name(node) {
+            Some(name) => format!("function `{name}`"),
+            None => "anonymous function".to_string(),
+        };
+        // Point at the signature line rather than the whole body
+        let first_line_end = ctx.source[node.start_byte()..node.end_byte()]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(node.end_byte(), |i| node.start_byte() + i);
+        let start = node.start_position();
+        Some(Finding {
+            rule_id: "",
+            message: format!("{subject} is too large: {}", exceeded.join(", ")),
+            severity: Severity::Warn,
+            file: ctx.file_path.to_path_buf(),
+            line: start.row + 1,
+            column: start.column + 1,
+            span: node.start_byte()..first_line_end,
+            suggestion: Some(
+                "Split it into smaller functions with one responsibility each.".to_string(),
+            ),
+            fix: None,
+            metrics: Some(metrics),
+            related: Vec::new(),
+        })
+    }
+}
+
+fn is_function(ctx: &RuleContext, node: tree_sitter::Node) -> bool {
+    ctx.is(node, SemanticNode::Function) || ctx.is(node, SemanticNode::Closure)
+}
+
+fn measure(ctx: &RuleContext, function: tree_sitter::Node) -> FunctionMetrics {
+    let mut metrics = FunctionMetrics {
+        lines: function.end_position().row - function.start_position().row + 1,
+        statements: 0,
+        complexity: 1,
+        parameters: count_parameters(ctx, function),
+    };
+    if let Some(body) = function.child_by_field_name("body") {
+        count_body(ctx, body, &mut metrics);
+    }
+    metrics
+}
+
+/// Adds the statements and decision points under `node` to `metrics`,
+/// skipping nested functions.
+fn count_body(ctx: &RuleContext, node: tree_sitter::Node, metrics: &mut FunctionMetrics) {
+    if ctx.is(node, SemanticNode::Branch) {
+        metrics.complexity += 1;
+    }
+
+    let mut cursor = node.walk();
+    if !cursor.goto_first_child() {
+        return;
+    }
+    loop {
+        let child = cursor.node();
+        if is_statement(ctx, node, child, cursor.field_name()) {
+            metrics.statements += 1;
+        }
+        if !is_function(ctx, child) {
+            count_body(ctx, child, metrics);
+        }
+        if !cursor.goto_next_sibling() {
+            return;
+        }
+    }
+}
+
+/// A statement is a child of a block, or the unbraced body of a loop or
+/// `case` (`for (...) run();`, `case 1: run();`).
+fn is_statement(ctx: &RuleContext, parent: tree_sitter::Node, child: tree_sitter::Node, field: Option<&str>) -> bool {
+    if !child.is_named() || ctx.is(child, SemanticNode::Comment) {
+        return false;
+    }
+    ctx.is(parent, SemanticNode::Block)
+        || (field == Some("body") && !ctx.is(child, SemanticNode::Block) && !is_function(ctx, parent))
+}
+
+fn count_parameters(ctx: &RuleContext, function: tree_sitter::Node) -> usize {
+    if function.child_by_field_name("parameter").is_some() {
+        // `x => x`
+        return 1;
+    }
+    let Some(parameters) = function.child_by_field_name("parameters") else {
+        return 0;
+    };
+    let mut cursor = parameters.walk();
+    parameters
+        .named_children(&mut cursor)
+        .filter(|p| !ctx.is(*p, SemanticNode::Comment))
+        .filter(|p| !p.utf8_text(ctx.source).is_ok_and(is_receiver))
+        .count()
+}
+
+/// `self`, `&mut self`, `&'a self` and Python's `cls` are not real parameters.
+fn is_receiver(parameter: &str) -> bool {
+    parameter == "cls"
+        || parameter
+            .rsplit(|c: char| c == '&' || c.is_whitespace())
+            .next()
+            .is_some_and(|last| last == "self")
+}
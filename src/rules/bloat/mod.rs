@@ -0,0 +1,7 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+pub mod deep_nesting;
+pub mod duplicate_code;
+pub mod god_function;
@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::parsers::SemanticNode;
+use crate::rules::RuleContext;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ops::Range;
+
+/// One leaf of the syntax tree with names and literal values abstracted
+/// away, so renamed copies of the same code produce the same sequence.
+#[derive(Debug, Clone)]
+pub struct CodeToken {
+    /// Hash of the token's normalized form: its node kind for keywords and
+    /// punctuation, a placeholder for names and literals.
+    pub hash: u64,
+    pub span: Range<usize>,
+    /// 0-based position, as tree-sitter reports positions.
+    pub row: usize,
+    pub column: usize,
+}
+
+/// The normalized tokens of every function body in the file, in source
+/// order. Code outside functions (imports, constants, type declarations) is
+/// left out because it repeats across files by design; the end of each
+/// top-level function is marked with a boundary token.
+pub fn function_tokens(ctx: &RuleContext) -> Vec<CodeToken> {
+    let mut tokens = Vec::new();
+    let mut cursor = ctx.tree.walk();
+    let mut depth = 0;
+    let boundary = hash_str("\u{0}boundary");
+    loop {
+        let node = cursor.node();
+        let is_function = ctx.is(node, SemanticNode::Function) || ctx.is(node, SemanticNode::Closure);
+        if is_function {
+            depth += 1;
+        }
+        if depth > 0 && !ctx.is(node, SemanticNode::Comment) && node.child_count() == 0 {
+            let normalized = if ctx.is(node, SemanticNode::Identifier) {
+                "\u{0}identifier"
+            } else if node.is_named() {
+                "\u{0}literal"
+            } else {
+                node.kind()
+            };
+            let start = node.start_position();
+            tokens.push(CodeToken {
+                hash: hash_str(normalized),
+                span: node.byte_range(),
+                row: start.row,
+                column: start.column,
+            });
+        }
+
+        // Comments have no children worth visiting; skipping them keeps
+        // commented code out of the stream
+        if !ctx.is(node, SemanticNode::Comment) && cursor.goto_first_child() {
+            continue;
+        }
+        loop {
+            let node = cursor.node();
+            if ctx.is(node, SemanticNode::Function) || ctx.is(node, SemanticNode::Closure) {
+                depth -= 1;
+                if depth == 0 {
+                    let end = node.end_position();
+                    tokens.push(CodeToken {
+                        hash: boundary,
+                        span: node.end_byte()..node.end_byte(),
+                        row: end.row,
+                        column: end.column,
+                    });
+                }
+            }
+            if cursor.goto_next_sibling() {
+                break;
+            }
+            if !cursor.goto_parent() {
+                return tokens;
+            }
+        }
+    }
+}
+
+fn hash_str(s: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    s.hash(&mut hasher);
+    hasher.finish()
+}
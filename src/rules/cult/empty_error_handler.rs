@@ -0,0 +1,261 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::parsers::SemanticNode;
+use crate::rules::{Rule, RuleContext, RuleDocs};
+use crate::suppression::is_directive;
+use crate::tokens::comment_body;
+use crate::types::{Category, Finding, Severity};
+
+/// Words in a comment inside the handler that mark the swallow as deliberate.
+/// Matched case-insensitively at the start of the comment or of one of its
+/// clauses, so "unexpected" or "don't ignore" do not count.
+const IGNORE_ANNOTATIONS: &[&str] = &["intentional", "ignore", "deliberate", "expected", "safe to"];
+
+/// Punctuation that starts a new clause in a comment.
+const CLAUSE_BREAKS: &[char] = &[',', ';', ':', '.', '(', '—'];
+
+/// Receivers whose method calls only log: `console.error(e)`, `logger.warn(e)`.
+const LOG_RECEIVERS: &[&str] = &["console", "logger", "logging", "log"];
+
+/// Statements that do nothing: JS empty statements and Python `pass` / `...`.
+const NO_OP_STATEMENTS: &[&str] = &[";", "pass", "..."];
+
+/// What a handler does with the error it caught.
+enum Swallow {
+    Empty,
+    LogOnly,
+}
+
+pub struct EmptyErrorHandler;
+
+impl Rule for EmptyErrorHandler {
+    fn id(&self) -> &'static str {
+        "cult-001"
+    }
+    fn name(&self) -> &'static str {
+        "Empty Error Handler"
+    }
+    fn description(&self) -> &'static str {
+        "Detects error handlers that are empty or only log the error and swallow it"
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warn
+    }
+    fn category(&self) -> Category {
+        Category::CargoCult
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: "A try/catch that does nothing with the error looks like error handling \
+                but turns every failure into silent success. Logging and moving on is the same \
+                thing with extra output: the caller still carries on as if the call worked.",
+            heuristic: "Each `catch` / `except` clause, and each handler passed to a promise's \
+                `.catch(...)`, is flagged when its body is empty, holds only comments or no-op \
+                statements (`pass`, `;`), or only calls a logger (`console.*`, `logger.*`, \
+                `logging.*`, `log.*`, `print`). Handlers that rethrow, return a value or do any \
+                other work are left alone, as are handlers containing a comment that says the \
+                error is intentionally ignored: the comment, or a clause of it, starts with \
+                \"intentional\", \"ignore\", \"deliberate\", \"expected\" or \"safe to\".",
+            flagged_example: "try {\n  saveDraft(draft);\n} catch (err) {\n  console.error(err);\n}",
+            not_flagged_example: "try {\n  saveDraft(draft);\n} catch (err) {\n  throw new DraftError(\"could not save draft\", { cause: err });\n}",
+            options: &[],
+            false_positives: &[
+                "Handlers whose comment explains why the error is harmless without using one of the annotation words",
+                "Best-effort calls (telemetry, cache warm-up) where logging and continuing is the intended behaviour",
+            ],
+        }
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        let mut cursor = ctx.tree.walk();
+        Self::walk_tree(ctx, &mut cursor, &mut findings);
+        findings
+    }
+}
+
+impl EmptyErrorHandler {
+    fn walk_tree(ctx: &RuleContext, cursor: &mut tree_sitter::TreeCursor, findings: &mut Vec<Finding>) {
+        loop {
+            let node = cursor.node();
+
+            if ctx.is(node, SemanticNode::CatchClause) {
+                if let Some(body) = Self::clause_body(ctx, node)
+                    && let Some(swallow) = Self::swallows(ctx, node, body)
+                {
+                    findings.push(Self::finding(ctx, node, swallow));
+                }
+            } else if let Some(handler) = Self::promise_handler(ctx, node)
+                && let Some(swallow) = Self::handler_swallows(ctx, handler)
+            {
+                findings.push(Self::finding(ctx, handler, swallow));
+            }
+
+            if cursor.goto_first_child() {
+                continue;
+            }
+            if cursor.goto_next_sibling() {
+                continue;
+            }
+            loop {
+                if !cursor.goto_parent() {
+                    return;
+                }
+                if cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// The block of a catch clause. JavaScript names it `body`; Python's
+    /// `except` clause has it as its last child.
+    fn clause_body<'t>(ctx: &RuleContext, clause: tree_sitter::Node<'t>) -> Option<tree_sitter::Node<'t>> {
+        clause.child_by_field_name("body").or_else(|| {
+            let mut cursor = clause.walk();
+            clause
+                .named_children(&mut cursor)
+                .last()
+                .filter(|last| ctx.is(*last, SemanticNode::Block))
+        })
+    }
+
+    /// The first argument of a `<promise>.catch(...)` call.
+    fn promise_handler<'t>(ctx: &RuleContext, node: tree_sitter::Node<'t>) -> Option<tree_sitter::Node<'t>> {
+        if !ctx.is(node, SemanticNode::CallExpression) {
+            return None;
+        }
+        let callee = node.child_by_field_name("function")?.utf8_text(ctx.source).ok()?;
+        if !callee.ends_with(".catch") {
+            return None;
+        }
+        node.child_by_field_name("arguments")?.named_child(0)
+    }
+
+    /// Classifies a promise rejection handler: a function literal, or a bare
+    /// logger such as `console.error` passed by reference.
+    fn handler_swallows(ctx: &RuleContext, handler: tree_sitter::Node) -> Option<Swallow> {
+        if ctx.is(handler, SemanticNode::Closure) {
+            let body = handler.child_by_field_name("body")?;
+            if ctx.is(body, SemanticNode::Block) {
+                return Self::swallows(ctx, handler, body);
+            }
+            // Expression-bodied arrow: `err => console.error(err)` swallows,
+            // `() => fallback` returns a value
+            return (!Self::is_annotated(ctx, handler) && Self::is_log_call(ctx, body))
+                .then_some(Swallow::LogOnly);
+        }
+        let text = handler.utf8_text(ctx.source).ok()?;
+        is_logger(text).then_some(Swallow::LogOnly)
+    }
+
+    fn swallows(ctx: &RuleContext, handler: tree_sitter::Node, body: tree_sitter::Node) -> Option<Swallow> {
+        if Self::is_annotated(ctx, handler) {
+            return None;
+        }
+
+        let mut cursor = body.walk();
+        let statements: Vec<tree_sitter::Node> = body
+            .named_children(&mut cursor)
+            .filter(|n| !ctx.is(*n, SemanticNode::Comment))
+            .filter(|n| {
+                n.utf8_text(ctx.source)
+                    .is_ok_and(|text| !NO_OP_STATEMENTS.contains(&text.trim()))
+            })
+            .collect();
+
+        if statements.is_empty() {
+            Some(Swallow::Empty)
+        } else if statements.iter().all(|s| Self::is_log_call(ctx, *s)) {
+            Some(Swallow::LogOnly)
+        } else {
+            None
+        }
+    }
+
+    /// True if any comment inside the handler says the error is ignored on purpose.
+    fn is_annotated(ctx: &RuleContext, handler: tree_sitter::Node) -> bool {
+        let mut cursor = handler.walk();
+        loop {
+            let node = cursor.node();
+            if ctx.is(node, SemanticNode::Comment)
+                && let Ok(text) = node.utf8_text(ctx.source)
+                && !is_directive(text)
+            {
+                let lower = comment_body(text).to_lowercase();
+                let annotated = lower.split(CLAUSE_BREAKS).map(str::trim_start).any(|clause| {
+                    IGNORE_ANNOTATIONS.iter().any(|word| clause.starts_with(word))
+                });
+                if annotated {
+                    return true;
+                }
+            }
+
+            if cursor.goto_first_child() {
+                continue;
+            }
+            if cursor.goto_next_sibling() {
+                continue;
+            }
+            loop {
+                if !cursor.goto_parent() {
+                    return false;
+                }
+                if cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// True for a logger call, either bare or as an expression statement.
+    fn is_log_call(ctx: &RuleContext, node: tree_sitter::Node) -> bool {
+        let call = if node.kind() == "expression_statement" {
+            node.named_child(0)
+        } else {
+            Some(node)
+        };
+        call.filter(|call| ctx.is(*call, SemanticNode::CallExpression))
+            .and_then(|call| call.child_by_field_name("function"))
+            .and_then(|callee| callee.utf8_text(ctx.source).ok())
+            .is_some_and(is_logger)
+    }
+
+    fn finding(ctx: &RuleContext, node: tree_sitter::Node, swallow: Swallow) -> Finding {
+        let message = match swallow {
+            Swallow::Empty => "error handler is empty and silently swallows the error",
+            Swallow::LogOnly => "error handler only logs the error and then swallows it",
+        };
+        let start = node.start_position();
+        Finding {
+            rule_id: "",
+            message: message.to_string(),
+            severity: Severity::Warn,
+            file: ctx.file_path.to_path_buf(),
+            line: start.row + 1,
+            column: start.column + 1,
+            span: node.byte_range(),
+            suggestion: Some(
+                "Handle the error, rethrow it, or add a comment explaining why it is safe to ignore."
+                    .to_string(),
+            ),
+            fix: None,
+            metrics: None,
+            related: Vec::new(),
+        }
+    }
+}
+
+/// True for `print` and for methods on a logging receiver, including
+/// `this.logger.error`.
+fn is_logger(callee: &str) -> bool {
+    if callee == "print" {
+        return true;
+    }
+    callee
+        .rsplit_once('.')
+        .map(|(receiver, _)| receiver.rsplit('.').next().unwrap_or(receiver))
+        .is_some_and(|receiver| LOG_RECEIVERS.contains(&receiver))
+}
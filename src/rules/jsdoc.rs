@@ -0,0 +1,332 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::parsers::SemanticNode;
+use crate::rules::RuleContext;
+use std::ops::Range;
+
+/// Tags whose first word after the type is a parameter name.
+const PARAM_TAGS: &[&str] = &["param", "arg", "argument"];
+
+/// One line of a JSDoc block with its `/**`, `*` and `*/` decoration removed.
+#[derive(Debug)]
+pub struct DocLine<'a> {
+    pub text: &'a str,
+    /// Byte range of `text` in the source file.
+    pub span: Range<usize>,
+    /// 0-based position of `text`, as tree-sitter reports positions.
+    pub row: usize,
+    pub column: usize,
+}
+
+impl<'a> DocLine<'a> {
+    /// The part of this line occupied by `part`, which must be a slice of
+    /// `text`.
+    pub fn sub(&self, part: &'a str) -> DocLine<'a> {
+        let offset = part.as_ptr() as usize - self.text.as_ptr() as usize;
+        DocLine {
+            text: part,
+            span: self.span.start + offset..self.span.start + offset + part.len(),
+            row: self.row,
+            column: self.column + offset,
+        }
+    }
+}
+
+/// A block tag such as `@param {string} name - The user's name`.
+#[derive(Debug)]
+pub struct Tag<'a> {
+    /// Tag name without the `@`: `param`, `returns`, ...
+    pub name: &'a str,
+    /// The type expression without braces, if any.
+    pub type_expr: Option<&'a str>,
+    /// For `@param`: the parameter name without `[]` or a default value,
+    /// including the path of a documented property: `options.name`.
+    pub param: Option<&'a str>,
+    /// Free text after the name, including continuation lines.
+    pub description: String,
+    /// The line the tag starts on.
+    pub line: DocLine<'a>,
+}
+
+#[derive(Debug)]
+pub struct JsDoc<'a> {
+    /// Lines before the first tag, including blank lines between paragraphs.
+    pub description: Vec<DocLine<'a>>,
+    pub tags: Vec<Tag<'a>>,
+}
+
+impl JsDoc<'_> {
+    /// Number of paragraphs in the free-text description.
+    pub fn paragraphs(&self) -> usize {
+        let mut count = 0;
+        let mut in_paragraph = false;
+        for line in &self.description {
+            if line.text.is_empty() {
+                in_paragraph = false;
+            } else if !in_paragraph {
+                in_paragraph = true;
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// The `@param` tags, in order.
+    pub fn params(&self) -> impl Iterator<Item = &Tag<'_>> {
+        self.tags.iter().filter(|tag| PARAM_TAGS.contains(&tag.name))
+    }
+
+    /// The `@returns` or `@return` tag.
+    pub fn returns(&self) -> Option<&Tag<'_>> {
+        self.tags.iter().find(|tag| tag.name == "returns" || tag.name == "return")
+    }
+}
+
+/// Parses `comment` if it is a `/** */` block.
+pub fn parse<'a>(comment: tree_sitter::Node, source: &'a [u8]) -> Option<JsDoc<'a>> {
+    let text = comment.utf8_text(source).ok()?;
+    if !text.starts_with("/**") || text.starts_with("/**/") || text.starts_with("/***") {
+        return None;
+    }
+
+    let mut doc = JsDoc { description: Vec::new(), tags: Vec::new() };
+    let mut offset = 0;
+    for (i, raw) in text.split_inclusive('\n').enumerate() {
+        let raw_start = offset;
+        offset += raw.len();
+
+        let mut content = raw.trim_end();
+        if i == 0 {
+            content = &content[3..];
+        }
+        content = content.strip_suffix("*/").unwrap_or(content).trim_end();
+        let trimmed = content.trim_start();
+        let content = trimmed.strip_prefix('*').unwrap_or(trimmed).trim();
+
+        // `content` is a subslice of `raw`, so its offset locates it in the file
+        let start_in_raw = if content.is_empty() {
+            raw.len() - raw.trim_start().len()
+        } else {
+            content.as_ptr() as usize - raw.as_ptr() as usize
+        };
+        let span_start = comment.start_byte() + raw_start + start_in_raw;
+        let line = DocLine {
+            text: content,
+            span: span_start..span_start + content.len(),
+            row: comment.start_position().row + i,
+            column: if i == 0 { comment.start_position().column + start_in_raw } else { start_in_raw },
+        };
+
+        if let Some(tag) = content.strip_prefix('@') {
+            doc.tags.push(parse_tag(tag, line));
+        } else if let Some(tag) = doc.tags.last_mut() {
+            if !content.is_empty() {
+                if !tag.description.is_empty() {
+                    tag.description.push(' ');
+                }
+                tag.description.push_str(content);
+            }
+        } else {
+            doc.description.push(line);
+        }
+    }
+
+    // Drop the blank lines left by `/**` and `*/` on their own lines
+    while doc.description.first().is_some_and(|l| l.text.is_empty()) {
+        doc.description.remove(0);
+    }
+    while doc.description.last().is_some_and(|l| l.text.is_empty()) {
+        doc.description.pop();
+    }
+    Some(doc)
+}
+
+fn parse_tag<'a>(tag: &'a str, line: DocLine<'a>) -> Tag<'a> {
+    let (name, mut rest) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+    rest = rest.trim_start();
+
+    let mut type_expr = None;
+    if rest.starts_with('{') {
+        let mut depth = 0;
+        for (i, c) in rest.char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                type_expr = Some(rest[1..i].trim());
+                rest = rest[i + 1..].trim_start();
+                break;
+            }
+        }
+    }
+
+    let mut param = None;
+    if PARAM_TAGS.contains(&name) {
+        let (word, after) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        let word = word.trim_start_matches('[').trim_end_matches(']');
+        let word = word.split_once('=').map_or(word, |(name, _)| name);
+        param = Some(word).filter(|p| !p.is_empty());
+        rest = after.trim_start();
+    }
+    let description = rest.strip_prefix('-').unwrap_or(rest).trim().to_string();
+
+    Tag { name, type_expr, param, description, line }
+}
+
+/// The function a doc comment documents: the next sibling when it is a
+/// function, method or arrow function, looking through `export` and
+/// `const name = ...` declarations.
+pub fn documented_function<'t>(
+    ctx: &RuleContext,
+    comment: tree_sitter::Node<'t>,
+) -> Option<tree_sitter::Node<'t>> {
+    let mut node = comment.next_named_sibling()?;
+    if ctx.is(node, SemanticNode::Comment) {
+        return None;
+    }
+    for _ in 0..3 {
+        if ctx.is(node, SemanticNode::Function) || ctx.is(node, SemanticNode::Closure) {
+            return Some(node);
+        }
+        node = node
+            .child_by_field_name("declaration")
+            .or_else(|| node.child_by_field_name("value"))
+            .or_else(|| node.named_child(0).and_then(|n| n.child_by_field_name("value")))?;
+    }
+    None
+}
+
+/// A formal parameter as JSDoc refers to it.
+#[derive(Debug)]
+pub struct Parameter<'t> {
+    pub node: tree_sitter::Node<'t>,
+    /// The bound name; `None` for a destructured parameter, which JSDoc may
+    /// document under any name.
+    pub name: Option<&'t str>,
+    /// The property names a destructured object takes apart, or `None` when
+    /// they are unknown (array patterns, `...rest` inside the pattern).
+    pub keys: Option<Vec<&'t str>>,
+}
+
+/// The formal parameters of `function`, in order. Default values, type
+/// annotations and TypeScript's `?` are looked through; `this` and `self` are
+/// skipped.
+pub fn parameters<'t>(
+    ctx: &RuleContext<'t>,
+    function: tree_sitter::Node<'t>,
+) -> Vec<Parameter<'t>> {
+    let nodes: Vec<_> = if let Some(single) = function.child_by_field_name("parameter") {
+        vec![single]
+    } else if let Some(list) = function.child_by_field_name("parameters") {
+        let mut cursor = list.walk();
+        list.named_children(&mut cursor).collect()
+    } else {
+        Vec::new()
+    };
+
+    nodes
+        .into_iter()
+        .filter(|node| !ctx.is(*node, SemanticNode::Comment))
+        .filter_map(|node| {
+            let mut pattern = node;
+            while let Some(inner) = ["pattern", "left", "name"]
+                .iter()
+                .find_map(|field| pattern.child_by_field_name(field))
+            {
+                pattern = inner;
+            }
+            if ctx.is(pattern, SemanticNode::RestPattern) {
+                pattern = pattern.named_child(0)?;
+            }
+            if ctx.is(pattern, SemanticNode::DestructuringPattern) {
+                return Some(Parameter { node, name: None, keys: destructured_keys(ctx, pattern) });
+            }
+            let name = pattern.utf8_text(ctx.source).ok()?;
+            (ctx.is(pattern, SemanticNode::Identifier) && name != "self")
+                .then_some(Parameter { node, name: Some(name), keys: None })
+        })
+        .collect()
+}
+
+/// The property names in `{ a, b: c, d = 1 }`.
+fn destructured_keys<'t>(ctx: &RuleContext<'t>, pattern: tree_sitter::Node<'t>) -> Option<Vec<&'t str>> {
+    if !pattern.utf8_text(ctx.source).ok()?.starts_with('{') {
+        return None;
+    }
+    let mut keys = Vec::new();
+    let mut cursor = pattern.walk();
+    for child in pattern.named_children(&mut cursor) {
+        if ctx.is(child, SemanticNode::Comment) {
+            continue;
+        }
+        let key = child
+            .child_by_field_name("key")
+            .or_else(|| child.child_by_field_name("left"))
+            .unwrap_or(child);
+        if !ctx.is(key, SemanticNode::Identifier) {
+            return None;
+        }
+        keys.push(key.utf8_text(ctx.source).ok()?);
+    }
+    Some(keys)
+}
+
+/// True if `function` returns a value anywhere outside nested functions, or
+/// is an arrow function with an expression body.
+pub fn returns_value(ctx: &RuleContext, function: tree_sitter::Node) -> bool {
+    let Some(body) = function.child_by_field_name("body") else {
+        return false;
+    };
+    if !ctx.is(body, SemanticNode::Block) {
+        return true;
+    }
+    has_valued_return(ctx, body)
+}
+
+fn has_valued_return(ctx: &RuleContext, node: tree_sitter::Node) -> bool {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor).any(|child| {
+        if ctx.is(child, SemanticNode::Function) || ctx.is(child, SemanticNode::Closure) {
+            return false;
+        }
+        (ctx.is(child, SemanticNode::Return) && child.named_child_count() > 0)
+            || has_valued_return(ctx, child)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::Language;
+
+    #[test]
+    fn test_parse_tags() {
+        let source = "/**\n * Greets a user.\n *\n * @param {string} [name=\"you\"] - Who to greet\n *   by name.\n * @returns {void}\n */\nfunction greet(name) {}\n";
+        let parser = Language::JavaScript.parser().unwrap();
+        let tree = parser.parse(source.as_bytes()).unwrap();
+        let comment = tree.root_node().named_child(0).unwrap();
+        let doc = parse(comment, source.as_bytes()).unwrap();
+
+        assert_eq!(doc.description.len(), 1);
+        assert_eq!(doc.description[0].text, "Greets a user.");
+        assert_eq!(doc.description[0].row, 1);
+        assert_eq!(&source[doc.description[0].span.clone()], "Greets a user.");
+
+        let param = doc.params().next().unwrap();
+        assert_eq!(param.type_expr, Some("string"));
+        assert_eq!(param.param, Some("name"));
+        assert_eq!(param.description, "Who to greet by name.");
+        assert_eq!(param.line.row, 3);
+        let name = param.line.sub(param.param.unwrap());
+        assert_eq!(&source[name.span.clone()], "name");
+        assert_eq!(name.column, 20);
+
+        let returns = doc.returns().unwrap();
+        assert_eq!(returns.type_expr, Some("void"));
+        assert_eq!(returns.description, "");
+    }
+}
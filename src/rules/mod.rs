@@ -2,25 +2,265 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
+pub mod bloat;
+pub mod code_tokens;
+pub mod cult;
+pub mod jsdoc;
+pub mod plugin;
+pub mod query;
 pub mod slop;
+pub mod uniform;
 
-use crate::types::{Finding, Severity};
-use std::path::Path;
+use crate::parsers::{Language, NodeTypeMap, SemanticNode, python};
+use crate::types::{Category, Finding, Severity};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::any::Any;
+use std::path::{Path, PathBuf};
+
+/// Parent fields that hold the name a function expression is bound to:
+/// `const name = ...`, `name = ...`, `let name = ...`, `{ name: ... }`.
+const BINDING_NAME_FIELDS: &[&str] = &["name", "left", "pattern", "key"];
+
+/// Long-form documentation shown by `patina rules --explain <id>`.
+#[derive(Debug, Serialize)]
+pub struct RuleDocs {
+    /// Why the pattern is worth flagging.
+    pub rationale: &'static str,
+    /// How the rule decides, including its thresholds.
+    pub heuristic: &'static str,
+    pub flagged_example: &'static str,
+    pub not_flagged_example: &'static str,
+    /// Keys accepted in `[rules.config.<id>]`.
+    pub options: &'static [RuleOption],
+    pub false_positives: &'static [&'static str],
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuleOption {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub description: &'static str,
+}
+
+/// What a project rule kept of one analyzed file. Trees are dropped once a
+/// file's rules have run, so `summary` is whatever the rule's `collect` took
+/// from the tree.
+pub struct ProjectFile<S> {
+    pub path: PathBuf,
+    pub summary: S,
+}
+
+/// A per-file summary with its type erased, as the engine stores it.
+pub type AnySummary = Box<dyn Any + Send>;
+
+/// A parsed file plus the grammar mapping rules use to stay language-agnostic.
+pub struct RuleContext<'a> {
+    pub source: &'a [u8],
+    pub tree: &'a tree_sitter::Tree,
+    pub file_path: &'a Path,
+    pub language: Language,
+    pub node_types: &'a NodeTypeMap,
+}
+
+impl<'a> RuleContext<'a> {
+    /// Returns true if `node`'s grammar type is one of those mapped to `semantic`.
+    pub fn is(&self, node: tree_sitter::Node, semantic: SemanticNode) -> bool {
+        self.node_types
+            .get(&semantic)
+            .is_some_and(|kinds| kinds.contains(&node.kind()))
+    }
+
+    /// Returns true for Python docstrings; other languages have none.
+    pub fn is_docstring(&self, node: tree_sitter::Node) -> bool {
+        self.language == Language::Python && python::is_docstring(node)
+    }
+
+    /// A function's own name, or the name it is bound to when it is the value
+    /// of a declaration, assignment or object property.
+    pub fn function_name(&self, function: tree_sitter::Node) -> Option<&'a str> {
+        let text = self.function_name_node(function)?.utf8_text(self.source).ok()?;
+        (!text.contains('\n')).then_some(text)
+    }
+
+    /// The node holding `function_name`'s result.
+    pub fn function_name_node<'t>(&self, function: tree_sitter::Node<'t>) -> Option<tree_sitter::Node<'t>> {
+        let name = match function.child_by_field_name("name") {
+            Some(name) => name,
+            None => {
+                let parent = function.parent()?;
+                let is_value = ["value", "right"]
+                    .iter()
+                    .any(|field| parent.child_by_field_name(field) == Some(function));
+                if !is_value {
+                    return None;
+                }
+                BINDING_NAME_FIELDS
+                    .iter()
+                    .find_map(|field| parent.child_by_field_name(field))?
+            }
+        };
+        Some(name)
+    }
+}
 
 pub trait Rule: Send + Sync {
     fn id(&self) -> &'static str;
     fn name(&self) -> &'static str;
     fn description(&self) -> &'static str;
     fn severity(&self) -> Severity;
-    fn check(&self, source: &[u8], tree: &tree_sitter::Tree, file_path: &Path) -> Vec<Finding>;
+    fn category(&self) -> Category;
+    fn docs(&self) -> RuleDocs;
+
+    /// Applies the `[rules.config.<id>]` table from `.patina.toml`.
+    /// Rules without options reject any keys.
+    fn configure(&mut self, config: &toml::Value) -> Result<(), String> {
+        match config.as_table() {
+            Some(table) if table.is_empty() => Ok(()),
+            _ => Err("this rule has no configurable options".to_string()),
+        }
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding>;
+
+    /// What the engine calls. Rules that can fail at run time, such as
+    /// plugins, override it so a failure fails the scan instead of quietly
+    /// dropping the rule's findings.
+    fn try_check(&self, ctx: &RuleContext) -> Result<Vec<Finding>, String> {
+        Ok(self.check(ctx))
+    }
+
+    /// The project-level half of rules that also implement `ProjectRule`,
+    /// which return `Some(self)` here.
+    fn project(&self) -> Option<&dyn DynProjectRule> {
+        None
+    }
+}
+
+/// A rule that looks across files. Each file is first handed to `collect`
+/// during the parallel per-file pass, and the summaries it returns are passed
+/// to `finalize` once every file has been analyzed. Findings may point into
+/// any file and carry related locations in others; each is still subject to
+/// the suppressions of the file it is reported in.
+pub trait ProjectRule: Rule {
+    type Summary: Send + 'static;
+
+    /// Extracts what `finalize` needs from one file, or `None` to leave the
+    /// file out.
+    fn collect(&self, ctx: &RuleContext) -> Option<Self::Summary>;
+
+    /// Checks the project as a whole, with files in scan order.
+    fn finalize(&self, files: &[ProjectFile<Self::Summary>]) -> Vec<Finding>;
+}
+
+/// Object-safe form of `ProjectRule`, so the engine can drive project rules
+/// through `dyn Rule`. Implemented for every `ProjectRule`.
+pub trait DynProjectRule: Send + Sync {
+    fn collect_any(&self, ctx: &RuleContext) -> Option<AnySummary>;
+    fn finalize_any(&self, files: Vec<ProjectFile<AnySummary>>) -> Vec<Finding>;
+}
+
+impl<R: ProjectRule> DynProjectRule for R {
+    fn collect_any(&self, ctx: &RuleContext) -> Option<AnySummary> {
+        self.collect(ctx).map(|summary| Box::new(summary) as AnySummary)
+    }
+
+    fn finalize_any(&self, files: Vec<ProjectFile<AnySummary>>) -> Vec<Finding> {
+        let files: Vec<ProjectFile<R::Summary>> = files
+            .into_iter()
+            .map(|file| ProjectFile {
+                path: file.path,
+                summary: *file.summary.downcast().expect("summary was collected by this rule"),
+            })
+            .collect();
+        self.finalize(&files)
+    }
 }
 
 pub fn all_rules() -> Vec<Box<dyn Rule>> {
     vec![
-        Box::new(slop::redundant_comment::RedundantComment),
+        Box::new(slop::redundant_comment::RedundantComment::default()),
         Box::new(slop::reasoning_artifact::ReasoningArtifact),
-        Box::new(slop::filler_hedge::FillerHedge),
-        Box::new(slop::commented_out_code::CommentedOutCode),
+        Box::new(slop::filler_hedge::FillerHedge::default()),
+        Box::new(slop::commented_out_code::CommentedOutCode::default()),
         Box::new(slop::self_narrating::SelfNarrating),
+        Box::new(slop::section_banner::SectionBanner::default()),
+        Box::new(slop::debug_residue::DebugResidue::default()),
+        Box::new(slop::over_documentation::OverDocumentation),
+        Box::new(slop::jsdoc_drift::JsDocDrift),
+        Box::new(cult::empty_error_handler::EmptyErrorHandler),
+        Box::new(bloat::deep_nesting::DeepNesting::default()),
+        Box::new(bloat::god_function::GodFunction::default()),
+        Box::new(bloat::duplicate_code::DuplicateCode::default()),
+        Box::new(uniform::identical_structure::IdenticalStructure::default()),
+        Box::new(uniform::template_naming::TemplateNaming::default()),
     ]
 }
+
+/// Rules loaded at runtime live for the whole run; leaking their strings
+/// lets them fill the same `&'static str` slots as built-in rules.
+pub fn leak(text: String) -> &'static str {
+    Box::leak(text.into_boxed_str())
+}
+
+/// Deserializes a rule's options table, rejecting unknown keys when `T` uses
+/// `#[serde(deny_unknown_fields)]`.
+pub fn parse_options<T: DeserializeOwned>(config: &toml::Value) -> Result<T, String> {
+    config.clone().try_into().map_err(|e: toml::de::Error| e.message().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_doc_examples_match_rule_behavior() {
+        let parser = Language::JavaScript.parser().unwrap();
+        for rule in all_rules() {
+            let docs = rule.docs();
+            let check = |example: &str| {
+                let Some(project) = rule.project() else {
+                    let tree = parser.parse(example.as_bytes()).unwrap();
+                    return rule.check(&RuleContext {
+                        source: example.as_bytes(),
+                        tree: &tree,
+                        file_path: Path::new("example.js"),
+                        language: Language::JavaScript,
+                        node_types: parser.node_types(),
+                    });
+                };
+                // Project rule examples are several files, each opened by `// file: <name>`
+                let files: Vec<ProjectFile<AnySummary>> = example
+                    .split("// file: ")
+                    .filter(|file| !file.is_empty())
+                    .filter_map(|file| {
+                        let (name, source) = file.split_once('\n').unwrap();
+                        let tree = parser.parse(source.as_bytes()).unwrap();
+                        let summary = project.collect_any(&RuleContext {
+                            source: source.as_bytes(),
+                            tree: &tree,
+                            file_path: Path::new(name),
+                            language: Language::JavaScript,
+                            node_types: parser.node_types(),
+                        })?;
+                        Some(ProjectFile {
+                            path: PathBuf::from(name),
+                            summary,
+                        })
+                    })
+                    .collect();
+                project.finalize_any(files)
+            };
+            assert!(
+                !check(docs.flagged_example).is_empty(),
+                "{}: flagged example should produce a finding",
+                rule.id()
+            );
+            assert!(
+                check(docs.not_flagged_example).is_empty(),
+                "{}: not-flagged example should produce no findings",
+                rule.id()
+            );
+        }
+    }
+}
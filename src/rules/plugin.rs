@@ -0,0 +1,473 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Rules loaded from WebAssembly modules, so rule packs can be shared without
+//! building patina. Modules run in an embedded interpreter with no imports, a
+//! fuel budget and a memory cap, and get a fresh instance for every call.
+//! The ABI is described in `docs/PLUGINS.md`.
+
+use crate::parsers::{Language, SemanticNode};
+use crate::rules::{Rule, RuleContext, RuleDocs, leak};
+use crate::types::{Category, Finding, Severity};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use wasmi::{Engine, Instance, Linker, Memory, Module, Store, StoreLimits, StoreLimitsBuilder, TypedFunc, WasmParams, WasmResults};
+
+/// The plugin ABI this build speaks; modules report theirs from
+/// `patina_abi_version`.
+pub const ABI_VERSION: i32 = 1;
+
+/// Roughly the number of instructions one call may execute.
+const FUEL: u64 = 1_000_000_000;
+
+/// The most linear memory one call may grow to.
+const MAX_MEMORY: usize = 256 << 20;
+
+/// A compiled module, shared by the rules it defines.
+struct Plugin {
+    path: PathBuf,
+    engine: Engine,
+    module: Module,
+    /// Fuel each call starts with.
+    fuel: u64,
+}
+
+/// One instance of a plugin, discarded after the call it was made for.
+struct Call {
+    store: Store<StoreLimits>,
+    instance: Instance,
+    memory: Memory,
+}
+
+/// A rule as described by `patina_rules`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Metadata {
+    id: String,
+    name: String,
+    description: String,
+    rationale: Option<String>,
+    severity: Severity,
+    category: Category,
+    /// Language IDs the rule runs on; all languages when empty.
+    #[serde(default)]
+    languages: Vec<String>,
+    /// Whether `patina_check` needs the syntax tree, which is the bulk of the input.
+    #[serde(default)]
+    tree: bool,
+}
+
+/// What `patina_check` receives.
+#[derive(Serialize)]
+struct Input<'a> {
+    path: &'a Path,
+    language: &'static str,
+    source: std::borrow::Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tree: Option<Vec<TreeNode>>,
+}
+
+/// One syntax node; the tree is sent flattened in pre-order.
+#[derive(Serialize)]
+struct TreeNode {
+    kind: &'static str,
+    named: bool,
+    /// The node's field name in its parent, such as `"name"` or `"body"`.
+    field: Option<&'static str>,
+    /// Index of the parent node in the list.
+    parent: Option<usize>,
+    start_byte: usize,
+    end_byte: usize,
+    /// 0-based `[row, column]`, as tree-sitter reports positions.
+    start: [usize; 2],
+    end: [usize; 2],
+    semantic: Vec<SemanticNode>,
+}
+
+/// One finding as returned by `patina_check`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Output {
+    start_byte: usize,
+    end_byte: usize,
+    message: String,
+    suggestion: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct PluginRule {
+    id: &'static str,
+    name: &'static str,
+    description: &'static str,
+    rationale: &'static str,
+    heuristic: &'static str,
+    severity: Severity,
+    category: Category,
+    languages: Vec<Language>,
+    tree: bool,
+    plugin: Arc<Plugin>,
+    /// The rule's position in `patina_rules`, passed back to `patina_check`.
+    index: i32,
+}
+
+/// Loads every rule defined by the plugins in `files`.
+pub fn load(files: &[PathBuf]) -> Result<Vec<PluginRule>, String> {
+    let mut rules = Vec::new();
+    for file in files {
+        let bytes = std::fs::read(file).map_err(|e| format!("Failed to read {}: {e}", file.display()))?;
+        let loaded = Plugin::compile(file, &bytes)
+            .and_then(|plugin| plugin.rules())
+            .map_err(|e| format!("Invalid plugin {}: {e}", file.display()))?;
+        rules.extend(loaded);
+    }
+    Ok(rules)
+}
+
+impl Plugin {
+    fn compile(path: &Path, bytes: &[u8]) -> Result<Self, String> {
+        let mut config = wasmi::Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, bytes).map_err(|e| e.to_string())?;
+        if let Some(import) = module.imports().next() {
+            return Err(format!(
+                "plugins may not import anything, but this one imports `{}::{}`",
+                import.module(),
+                import.name()
+            ));
+        }
+        Ok(Self {
+            path: path.to_path_buf(),
+            engine,
+            module,
+            fuel: FUEL,
+        })
+    }
+
+    fn instantiate(&self) -> Result<Call, String> {
+        let limits = StoreLimitsBuilder::new().memory_size(MAX_MEMORY).build();
+        let mut store = Store::new(&self.engine, limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(self.fuel).map_err(|e| e.to_string())?;
+        let instance = Linker::new(&self.engine)
+            .instantiate(&mut store, &self.module)
+            .and_then(|instance| instance.start(&mut store))
+            .map_err(|e| e.to_string())?;
+        let memory = instance
+            .get_memory(&store, "memory")
+            .ok_or("the module does not export `memory`")?;
+        Ok(Call {
+            store,
+            instance,
+            memory,
+        })
+    }
+
+    /// Checks the ABI version and builds a rule for each entry in `patina_rules`.
+    fn rules(self) -> Result<Vec<PluginRule>, String> {
+        let mut call = self.instantiate()?;
+        let version = call.func::<(), i32>("patina_abi_version")?.call(&mut call.store, ()).map_err(|e| e.to_string())?;
+        if version != ABI_VERSION {
+            return Err(format!("the module uses plugin ABI version {version}, but patina supports {ABI_VERSION}"));
+        }
+        let packed = call.func::<(), i64>("patina_rules")?.call(&mut call.store, ()).map_err(|e| e.to_string())?;
+        let metadata: Vec<Metadata> =
+            serde_json::from_slice(&call.read(packed)?).map_err(|e| format!("`patina_rules` returned invalid JSON: {e}"))?;
+
+        let plugin = Arc::new(self);
+        metadata
+            .into_iter()
+            .enumerate()
+            .map(|(index, rule)| {
+                let languages = rule
+                    .languages
+                    .iter()
+                    .map(|id| Language::from_id(id).ok_or_else(|| format!("rule `{}`: unknown language `{id}`", rule.id)))
+                    .collect::<Result<Vec<Language>, String>>()?;
+                let description = leak(rule.description);
+                Ok(PluginRule {
+                    id: leak(rule.id),
+                    name: leak(rule.name),
+                    description,
+                    rationale: rule.rationale.map_or(description, leak),
+                    heuristic: leak(format!("Plugin rule from {}.", plugin.path.display())),
+                    severity: rule.severity,
+                    category: rule.category,
+                    languages,
+                    tree: rule.tree,
+                    plugin: Arc::clone(&plugin),
+                    index: index as i32,
+                })
+            })
+            .collect()
+    }
+}
+
+impl Call {
+    fn func<Params: WasmParams, Results: WasmResults>(&self, name: &str) -> Result<TypedFunc<Params, Results>, String> {
+        self.instance
+            .get_typed_func::<Params, Results>(&self.store, name)
+            .map_err(|e| format!("`{name}`: {e}"))
+    }
+
+    /// Copies `bytes` into a buffer from `patina_alloc`, returning its pointer
+    /// and length.
+    fn write(&mut self, bytes: &[u8]) -> Result<(i32, i32), String> {
+        let len = i32::try_from(bytes.len()).map_err(|_| "input is too large for a plugin".to_string())?;
+        let ptr = self.func::<i32, i32>("patina_alloc")?.call(&mut self.store, len).map_err(|e| e.to_string())?;
+        self.memory
+            .write(&mut self.store, ptr as u32 as usize, bytes)
+            .map_err(|e| format!("`patina_alloc` returned an unusable buffer: {e}"))?;
+        Ok((ptr, len))
+    }
+
+    /// Reads the buffer a `(pointer << 32) | length` result points at.
+    fn read(&self, packed: i64) -> Result<Vec<u8>, String> {
+        let packed = packed as u64;
+        let (ptr, len) = ((packed >> 32) as usize, (packed & 0xffff_ffff) as usize);
+        let mut buffer = vec![0; len];
+        self.memory
+            .read(&self.store, ptr, &mut buffer)
+            .map_err(|e| format!("result is out of bounds: {e}"))?;
+        Ok(buffer)
+    }
+}
+
+impl PluginRule {
+    fn run(&self, ctx: &RuleContext) -> Result<Vec<Finding>, String> {
+        let input = serde_json::to_vec(&Input {
+            path: ctx.file_path,
+            language: ctx.language.id(),
+            source: String::from_utf8_lossy(ctx.source),
+            tree: self.tree.then(|| tree_nodes(ctx)),
+        })
+        .map_err(|e| e.to_string())?;
+
+        let mut call = self.plugin.instantiate()?;
+        let (ptr, len) = call.write(&input)?;
+        let packed = call
+            .func::<(i32, i32, i32), i64>("patina_check")?
+            .call(&mut call.store, (self.index, ptr, len))
+            .map_err(|e| e.to_string())?;
+        let outputs: Vec<Output> =
+            serde_json::from_slice(&call.read(packed)?).map_err(|e| format!("`patina_check` returned invalid JSON: {e}"))?;
+
+        outputs
+            .into_iter()
+            .map(|output| {
+                if output.start_byte > output.end_byte || output.end_byte > ctx.source.len() {
+                    return Err(format!(
+                        "finding span {}..{} is outside the file",
+                        output.start_byte, output.end_byte
+                    ));
+                }
+                let (line, column) = position(ctx.source, output.start_byte);
+                Ok(Finding {
+                    rule_id: "",
+                    message: output.message,
+                    severity: self.severity,
+                    file: ctx.file_path.to_path_buf(),
+                    line,
+                    column,
+                    span: output.start_byte..output.end_byte,
+                    suggestion: output.suggestion,
+                    fix: None,
+                    metrics: None,
+                    related: Vec::new(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl Rule for PluginRule {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+    fn name(&self) -> &'static str {
+        self.name
+    }
+    fn description(&self) -> &'static str {
+        self.description
+    }
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+    fn category(&self) -> Category {
+        self.category
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: self.rationale,
+            heuristic: self.heuristic,
+            flagged_example: "",
+            not_flagged_example: "",
+            options: &[],
+            false_positives: &[],
+        }
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        self.try_check(ctx).unwrap_or_default()
+    }
+
+    fn try_check(&self, ctx: &RuleContext) -> Result<Vec<Finding>, String> {
+        if !self.languages.is_empty() && !self.languages.contains(&ctx.language) {
+            return Ok(Vec::new());
+        }
+        self.run(ctx)
+    }
+}
+
+fn tree_nodes(ctx: &RuleContext) -> Vec<TreeNode> {
+    let mut nodes = Vec::new();
+    let mut parents: Vec<usize> = Vec::new();
+    let mut cursor = ctx.tree.walk();
+    loop {
+        let node = cursor.node();
+        let (start, end) = (node.start_position(), node.end_position());
+        nodes.push(TreeNode {
+            kind: node.kind(),
+            named: node.is_named(),
+            field: cursor.field_name(),
+            parent: parents.last().copied(),
+            start_byte: node.start_byte(),
+            end_byte: node.end_byte(),
+            start: [start.row, start.column],
+            end: [end.row, end.column],
+            semantic: SemanticNode::ALL.iter().copied().filter(|&semantic| ctx.is(node, semantic)).collect(),
+        });
+
+        if cursor.goto_first_child() {
+            parents.push(nodes.len() - 1);
+            continue;
+        }
+        loop {
+            if cursor.goto_next_sibling() {
+                break;
+            }
+            if !cursor.goto_parent() {
+                return nodes;
+            }
+            parents.pop();
+        }
+    }
+}
+
+/// The 1-based line and byte column of `offset`.
+fn position(source: &[u8], offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line_start = before.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+    (before.iter().filter(|&&b| b == b'\n').count() + 1, offset - line_start + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METADATA: &str = r#"[{"id":"pack-001","name":"Pack Rule","description":"Reports a fixed span","severity":"warn","category":"slop","languages":["javascript"]}]"#;
+    const FINDINGS: &str = r#"[{"start_byte":23,"end_byte":28,"message":"found `value`"}]"#;
+
+    /// A plugin whose `patina_check` runs `check`, which by default returns
+    /// `FINDINGS`.
+    fn plugin(version: i32, check: Option<&str>) -> Vec<u8> {
+        let findings = ((2048_i64) << 32) | FINDINGS.len() as i64;
+        let check = check.map_or_else(|| format!("(i64.const {findings})"), str::to_string);
+        wat::parse_str(format!(
+            r#"(module
+                (memory (export "memory") 1)
+                (global $heap (mut i32) (i32.const 4096))
+                (data (i32.const 0) "{metadata}")
+                (data (i32.const 2048) "{output}")
+                (func (export "patina_abi_version") (result i32) (i32.const {version}))
+                (func (export "patina_alloc") (param $size i32) (result i32)
+                    (local $ptr i32)
+                    (local.set $ptr (global.get $heap))
+                    (global.set $heap (i32.add (global.get $heap) (local.get $size)))
+                    (drop (memory.grow (i32.add (i32.shr_u (local.get $size) (i32.const 16)) (i32.const 1))))
+                    (local.get $ptr))
+                (func (export "patina_rules") (result i64) (i64.const {metadata_len}))
+                (func (export "patina_check") (param i32 i32 i32) (result i64) {check}))"#,
+            metadata = METADATA.replace('"', "\\\""),
+            output = FINDINGS.replace('"', "\\\""),
+            metadata_len = METADATA.len(),
+        ))
+        .unwrap()
+    }
+
+    fn check(rule: &PluginRule, language: Language, source: &str) -> Result<Vec<Finding>, String> {
+        let parser = language.parser().unwrap();
+        let tree = parser.parse(source.as_bytes()).unwrap();
+        let ctx = RuleContext {
+            source: source.as_bytes(),
+            tree: &tree,
+            file_path: Path::new("example.js"),
+            language,
+            node_types: parser.node_types(),
+        };
+        rule.try_check(&ctx)
+    }
+
+    fn rules(bytes: &[u8]) -> Result<Vec<PluginRule>, String> {
+        Plugin::compile(Path::new("pack.wasm"), bytes).and_then(Plugin::rules)
+    }
+
+    #[test]
+    fn test_plugin_rule_reports_findings() {
+        let rules = rules(&plugin(ABI_VERSION, None)).unwrap();
+        assert_eq!(rules.len(), 1);
+        let rule = &rules[0];
+        assert_eq!((rule.id(), rule.name(), rule.severity()), ("pack-001", "Pack Rule", Severity::Warn));
+
+        let findings = check(rule, Language::JavaScript, "const answer = 42;\nlet value = answer;\n").unwrap();
+        let found: Vec<(usize, usize, &str)> = findings.iter().map(|f| (f.line, f.column, f.message.as_str())).collect();
+        assert_eq!(found, vec![(2, 5, "found `value`")]);
+
+        assert!(check(rule, Language::Python, "value = 1\n").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_plugin_sandbox_limits() {
+        let spin = plugin(ABI_VERSION, Some("(loop $spin (br $spin)) (unreachable)"));
+        let mut spinning = Plugin::compile(Path::new("spin.wasm"), &spin).unwrap();
+        spinning.fuel = 100_000;
+        let rule = &spinning.rules().unwrap()[0];
+        let err = check(rule, Language::JavaScript, "let value = 1;\n").err().unwrap();
+        assert!(err.contains("fuel"), "{err}");
+
+        let rule = &rules(&plugin(ABI_VERSION, None)).unwrap()[0];
+        let err = check(rule, Language::JavaScript, "x").err().unwrap();
+        assert!(err.contains("outside the file"), "{err}");
+
+        let importing = wat::parse_str(r#"(module (import "env" "open" (func)) (memory (export "memory") 1))"#).unwrap();
+        let err = rules(&importing).err().unwrap();
+        assert!(err.contains("may not import anything") && err.contains("env::open"), "{err}");
+
+        let err = rules(&plugin(ABI_VERSION + 1, None)).err().unwrap();
+        assert!(err.contains("ABI version 2"), "{err}");
+    }
+
+    #[test]
+    fn test_tree_nodes_link_parents_and_semantics() {
+        let parser = Language::JavaScript.parser().unwrap();
+        let source = "// note\nfoo();\n";
+        let tree = parser.parse(source.as_bytes()).unwrap();
+        let nodes = tree_nodes(&RuleContext {
+            source: source.as_bytes(),
+            tree: &tree,
+            file_path: Path::new("example.js"),
+            language: Language::JavaScript,
+            node_types: parser.node_types(),
+        });
+        assert_eq!(nodes[0].kind, "program");
+        assert_eq!(nodes[0].parent, None);
+        assert_eq!(nodes[1].kind, "comment");
+        assert_eq!(nodes[1].semantic, vec![SemanticNode::Comment]);
+        let call = nodes.iter().position(|node| node.kind == "call_expression").unwrap();
+        assert_eq!(nodes[call].start, [1, 0]);
+        let callee = nodes.iter().find(|node| node.parent == Some(call) && node.field == Some("function")).unwrap();
+        assert_eq!(&source[callee.start_byte..callee.end_byte], "foo");
+    }
+}
@@ -0,0 +1,489 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Rules defined in TOML files as a tree-sitter query plus predicates on its
+//! captures, so teams can add house rules without building patina.
+
+use crate::parsers::{Language, SemanticNode};
+use crate::rules::{Rule, RuleContext, RuleDocs, leak};
+use crate::tokens;
+use crate::types::{Category, Finding, Severity};
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tree_sitter::{Query, QueryCursor, StreamingIterator};
+
+/// Share of a capture's words found in the other capture when `overlaps` is
+/// set without `min_overlap`.
+const MIN_OVERLAP: f64 = 0.5;
+
+/// A rule file: one or more `[[rule]]` tables.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleFile {
+    rule: Vec<Definition>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Definition {
+    id: String,
+    name: String,
+    description: String,
+    /// Why the pattern matters, for `patina rules --explain`; defaults to the description.
+    rationale: Option<String>,
+    severity: Severity,
+    category: Category,
+    /// Language IDs such as `"javascript"` or `"python"`.
+    languages: Vec<String>,
+    query: String,
+    /// The capture findings point at; by default, everything the match captured.
+    report: Option<String>,
+    /// Finding text, with `{capture}` replaced by the captured source.
+    message: String,
+    suggestion: Option<String>,
+    #[serde(default, rename = "where")]
+    predicates: Vec<PredicateDefinition>,
+    #[serde(default)]
+    flagged_example: String,
+    #[serde(default)]
+    not_flagged_example: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PredicateDefinition {
+    capture: String,
+    matches: Option<String>,
+    not_matches: Option<String>,
+    min_lines: Option<usize>,
+    max_lines: Option<usize>,
+    overlaps: Option<String>,
+    min_overlap: Option<f64>,
+}
+
+/// A condition every match must meet, on the text of one capture.
+#[derive(Clone)]
+struct Predicate {
+    capture: String,
+    matches: Option<Regex>,
+    not_matches: Option<Regex>,
+    min_lines: Option<usize>,
+    max_lines: Option<usize>,
+    /// Another capture, and the share of this capture's words it must contain.
+    overlaps: Option<(String, f64)>,
+}
+
+#[derive(Clone)]
+pub struct QueryRule {
+    id: &'static str,
+    name: &'static str,
+    description: &'static str,
+    severity: Severity,
+    category: Category,
+    rationale: &'static str,
+    heuristic: &'static str,
+    flagged_example: &'static str,
+    not_flagged_example: &'static str,
+    /// Shared between clones, since compiled queries cannot be copied.
+    queries: Arc<HashMap<Language, Query>>,
+    report: Option<String>,
+    message: String,
+    suggestion: Option<String>,
+    predicates: Vec<Predicate>,
+}
+
+/// The nodes one match captured under a name.
+struct Captured<'t> {
+    nodes: Vec<tree_sitter::Node<'t>>,
+}
+
+impl Captured<'_> {
+    fn text(&self, source: &[u8]) -> String {
+        self.nodes
+            .iter()
+            .filter_map(|node| node.utf8_text(source).ok())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn lines(&self) -> usize {
+        match (self.nodes.first(), self.nodes.last()) {
+            (Some(first), Some(last)) => last.end_position().row - first.start_position().row + 1,
+            _ => 0,
+        }
+    }
+
+    /// The span from the first node to the end of the last, and where it starts.
+    fn location(&self) -> Option<(Range<usize>, tree_sitter::Point)> {
+        let first = self.nodes.iter().min_by_key(|node| node.start_byte())?;
+        let end = self.nodes.iter().map(|node| node.end_byte()).max()?;
+        Some((first.start_byte()..end, first.start_position()))
+    }
+}
+
+/// Loads the query rules defined in each of `files`.
+pub fn load(files: &[PathBuf]) -> Result<Vec<QueryRule>, String> {
+    let mut rules = Vec::new();
+    for file in files {
+        let text = std::fs::read_to_string(file).map_err(|e| format!("Failed to read {}: {e}", file.display()))?;
+        let parsed: RuleFile =
+            toml::from_str(&text).map_err(|e| format!("Invalid rule file {}: {e}", file.display()))?;
+        for definition in parsed.rule {
+            let id = definition.id.clone();
+            let rule = QueryRule::compile(definition, file)
+                .map_err(|e| format!("Invalid rule `{id}` in {}: {e}", file.display()))?;
+            rules.push(rule);
+        }
+    }
+    Ok(rules)
+}
+
+impl QueryRule {
+    fn compile(definition: Definition, file: &Path) -> Result<Self, String> {
+        if definition.id.trim().is_empty() {
+            return Err("`id` must not be empty".to_string());
+        }
+        if definition.languages.is_empty() {
+            return Err("`languages` must name at least one language".to_string());
+        }
+
+        let mut queries = HashMap::new();
+        for id in &definition.languages {
+            let language = Language::from_id(id).ok_or_else(|| {
+                let known: Vec<&str> = Language::ALL.iter().map(|language| language.id()).collect();
+                format!("unknown language `{id}` (known languages: {})", known.join(", "))
+            })?;
+            let query = Query::new(&language.grammar(), &definition.query)
+                .map_err(|e| format!("query does not compile for {id}: {e}"))?;
+            queries.insert(language, query);
+        }
+
+        // Every language's query has the same captures, so check names once
+        let captures: Vec<&str> = queries.values().next().map(|query| query.capture_names().to_vec()).unwrap_or_default();
+        let known = |name: &str| -> Result<(), String> {
+            if captures.contains(&name) {
+                Ok(())
+            } else {
+                Err(format!("unknown capture `@{name}` (the query captures: {})", captures.join(", ")))
+            }
+        };
+        if let Some(report) = &definition.report {
+            known(report)?;
+        }
+        for placeholder in placeholders(&definition.message) {
+            known(placeholder)?;
+        }
+
+        let regex = |pattern: &Option<String>| -> Result<Option<Regex>, String> {
+            pattern
+                .as_deref()
+                .map(|pattern| Regex::new(pattern).map_err(|e| format!("invalid regex `{pattern}`: {e}")))
+                .transpose()
+        };
+        let mut predicates = Vec::new();
+        for predicate in &definition.predicates {
+            known(&predicate.capture)?;
+            if let Some(other) = &predicate.overlaps {
+                known(other)?;
+            }
+            let min_overlap = predicate.min_overlap.unwrap_or(MIN_OVERLAP);
+            if !(0.0..=1.0).contains(&min_overlap) {
+                return Err(format!("min_overlap must be from 0.0 to 1.0, got {min_overlap}"));
+            }
+            if predicate.min_overlap.is_some() && predicate.overlaps.is_none() {
+                return Err("`min_overlap` needs `overlaps`".to_string());
+            }
+            predicates.push(Predicate {
+                capture: predicate.capture.clone(),
+                matches: regex(&predicate.matches)?,
+                not_matches: regex(&predicate.not_matches)?,
+                min_lines: predicate.min_lines,
+                max_lines: predicate.max_lines,
+                overlaps: predicate.overlaps.clone().map(|other| (other, min_overlap)),
+            });
+        }
+
+        let heuristic = format!(
+            "Custom rule from {}. Reports each match of its tree-sitter query in {} that meets \
+             {} capture condition(s).",
+            file.display(),
+            definition.languages.join(", "),
+            predicates.len()
+        );
+        let description = leak(definition.description);
+        Ok(Self {
+            id: leak(definition.id),
+            name: leak(definition.name),
+            description,
+            severity: definition.severity,
+            category: definition.category,
+            rationale: definition.rationale.map_or(description, leak),
+            heuristic: leak(heuristic),
+            flagged_example: leak(definition.flagged_example),
+            not_flagged_example: leak(definition.not_flagged_example),
+            queries: Arc::new(queries),
+            report: definition.report,
+            message: definition.message,
+            suggestion: definition.suggestion,
+            predicates,
+        })
+    }
+
+    fn holds(&self, ctx: &RuleContext, captures: &HashMap<&str, Captured>) -> bool {
+        let empty = Captured { nodes: Vec::new() };
+        let get = |name: &str| captures.get(name).unwrap_or(&empty);
+        self.predicates.iter().all(|predicate| {
+            let captured = get(&predicate.capture);
+            let text = captured.text(ctx.source);
+            let lines = captured.lines();
+            predicate.matches.as_ref().is_none_or(|regex| regex.is_match(&text))
+                && predicate.not_matches.as_ref().is_none_or(|regex| !regex.is_match(&text))
+                && predicate.min_lines.is_none_or(|min| lines >= min)
+                && predicate.max_lines.is_none_or(|max| lines <= max)
+                && predicate.overlaps.as_ref().is_none_or(|(other, min_overlap)| {
+                    overlap(ctx, captured, get(other)) >= *min_overlap
+                })
+        })
+    }
+
+    /// Fills in the template's placeholders in one pass, so captured source
+    /// that happens to contain `{name}` is never substituted itself.
+    fn message(&self, ctx: &RuleContext, captures: &HashMap<&str, Captured>) -> String {
+        let mut message = String::new();
+        let mut rest = self.message.as_str();
+        while let Some(open) = rest.find('{') {
+            message.push_str(&rest[..open]);
+            let after = &rest[open + 1..];
+            let placeholder = after
+                .split_once('}')
+                .and_then(|(name, tail)| Some((captures.get(name)?, tail)));
+            let Some((captured, tail)) = placeholder else {
+                // Not a capture of this match; keep the brace and move on
+                message.push('{');
+                rest = after;
+                continue;
+            };
+            let text = captured.text(ctx.source);
+            match text.split_once('\n') {
+                Some((first, _)) => {
+                    message.push_str(first.trim_end());
+                    message.push('…');
+                }
+                None => message.push_str(&text),
+            }
+            rest = tail;
+        }
+        message.push_str(rest);
+        message
+    }
+}
+
+impl Rule for QueryRule {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+    fn name(&self) -> &'static str {
+        self.name
+    }
+    fn description(&self) -> &'static str {
+        self.description
+    }
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+    fn category(&self) -> Category {
+        self.category
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: self.rationale,
+            heuristic: self.heuristic,
+            flagged_example: self.flagged_example,
+            not_flagged_example: self.not_flagged_example,
+            options: &[],
+            false_positives: &[],
+        }
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let Some(query) = self.queries.get(&ctx.language) else {
+            return Vec::new();
+        };
+        let names = query.capture_names();
+        let mut findings: Vec<Finding> = Vec::new();
+        let mut reported: BTreeSet<(usize, usize)> = BTreeSet::new();
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(query, ctx.tree.root_node(), ctx.source);
+        while let Some(found) = matches.next() {
+            let mut captures: HashMap<&str, Captured> = HashMap::new();
+            for capture in found.captures {
+                captures
+                    .entry(names[capture.index as usize])
+                    .or_insert_with(|| Captured { nodes: Vec::new() })
+                    .nodes
+                    .push(capture.node);
+            }
+            if !self.holds(ctx, &captures) {
+                continue;
+            }
+
+            let reported_nodes = match &self.report {
+                Some(name) => captures.get(name.as_str()).map(|captured| captured.nodes.clone()),
+                None => Some(captures.values().flat_map(|captured| captured.nodes.iter().copied()).collect()),
+            };
+            let Some((span, start)) = reported_nodes.and_then(|nodes| Captured { nodes }.location()) else {
+                continue;
+            };
+            if !reported.insert((span.start, span.end)) {
+                continue;
+            }
+            findings.push(Finding {
+                rule_id: "",
+                message: self.message(ctx, &captures),
+                severity: self.severity,
+                file: ctx.file_path.to_path_buf(),
+                line: start.row + 1,
+                column: start.column + 1,
+                span,
+                suggestion: self.suggestion.clone(),
+                fix: None,
+                metrics: None,
+                related: Vec::new(),
+            });
+        }
+        findings
+    }
+}
+
+/// Share of `captured`'s words, stemmed, that also appear in `other`.
+fn overlap(ctx: &RuleContext, captured: &Captured, other: &Captured) -> f64 {
+    let words = |captured: &Captured| -> BTreeSet<String> {
+        let text = captured.text(ctx.source);
+        if captured.nodes.iter().all(|node| ctx.is(*node, SemanticNode::Comment)) {
+            tokens::extract_comment_tokens(&text).into_iter().collect()
+        } else {
+            let identifiers: Vec<&str> = text
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .filter(|word| !word.is_empty())
+                .collect();
+            tokens::extract_code_tokens(&identifiers).into_iter().collect()
+        }
+    };
+    let (mine, theirs) = (words(captured), words(other));
+    if mine.is_empty() {
+        return 0.0;
+    }
+    mine.intersection(&theirs).count() as f64 / mine.len() as f64
+}
+
+/// The `{name}` placeholders in a message template.
+fn placeholders(template: &str) -> impl Iterator<Item = &str> {
+    template.split('{').skip(1).filter_map(|part| part.split_once('}').map(|(name, _)| name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compile(toml: &str) -> Result<QueryRule, String> {
+        let mut file: RuleFile = toml::from_str(toml).map_err(|e| e.to_string())?;
+        QueryRule::compile(file.rule.remove(0), Path::new("rules/house.toml"))
+    }
+
+    fn check(rule: &QueryRule, source: &str) -> Vec<Finding> {
+        let parser = Language::JavaScript.parser().unwrap();
+        let tree = parser.parse(source.as_bytes()).unwrap();
+        rule.check(&RuleContext {
+            source: source.as_bytes(),
+            tree: &tree,
+            file_path: Path::new("example.js"),
+            language: Language::JavaScript,
+            node_types: parser.node_types(),
+        })
+    }
+
+    const NO_ALERT: &str = r#"
+        [[rule]]
+        id = "house-001"
+        name = "Window Alert"
+        description = "Flags alert() calls"
+        severity = "warn"
+        category = "slop"
+        languages = ["javascript", "typescript"]
+        query = "(call_expression function: (identifier) @callee arguments: (arguments) @args) @call"
+        report = "call"
+        message = "`{callee}` call with {args}"
+
+        [[rule.where]]
+        capture = "callee"
+        matches = "^(alert|confirm)$"
+    "#;
+
+    #[test]
+    fn test_query_rule_reports_matches_that_meet_predicates() {
+        let rule = compile(NO_ALERT).unwrap();
+        let findings = check(&rule, "alert(\"saved\");\nnotify(\"saved\");\n  confirm(ok);\n");
+        let found: Vec<(usize, usize, &str)> =
+            findings.iter().map(|f| (f.line, f.column, f.message.as_str())).collect();
+        assert_eq!(
+            found,
+            vec![(1, 1, "`alert` call with (\"saved\")"), (3, 3, "`confirm` call with (ok)")]
+        );
+    }
+
+    #[test]
+    fn test_query_rule_message_does_not_substitute_captured_text() {
+        let rule = compile(NO_ALERT).unwrap();
+        let findings = check(&rule, "alert(\"{callee} {args}\");\n");
+        assert_eq!(findings[0].message, "`alert` call with (\"{callee} {args}\")");
+    }
+
+    #[test]
+    fn test_query_rule_line_and_overlap_predicates() {
+        let rule = compile(
+            r#"
+            [[rule]]
+            id = "house-002"
+            name = "Restating Comment"
+            description = "Comments above a statement that repeat it"
+            severity = "info"
+            category = "slop"
+            languages = ["javascript"]
+            query = "((comment) @comment . (expression_statement) @code)"
+            report = "comment"
+            message = "comment repeats the code"
+
+            [[rule.where]]
+            capture = "comment"
+            max_lines = 1
+            overlaps = "code"
+            min_overlap = 0.6
+            "#,
+        )
+        .unwrap();
+        let source = "// save the user\nsaveUser(user);\n// retry after a network blip\nsaveUser(user);\n/*\n save user\n*/\nsaveUser(user);\n";
+        let lines: Vec<usize> = check(&rule, source).iter().map(|f| f.line).collect();
+        assert_eq!(lines, vec![1]);
+    }
+
+    #[test]
+    fn test_query_rule_errors_name_the_problem() {
+        let bad_query = NO_ALERT.replace("(identifier) @callee", "(identifer) @callee");
+        assert!(compile(&bad_query).err().unwrap().contains("query does not compile for javascript"));
+
+        let bad_capture = NO_ALERT.replace("{args}", "{arguments}");
+        let err = compile(&bad_capture).err().unwrap();
+        assert!(err.contains("unknown capture `@arguments`"), "{err}");
+
+        let bad_language = NO_ALERT.replace("\"typescript\"", "\"go\"");
+        assert!(compile(&bad_language).err().unwrap().contains("unknown language `go`"));
+
+        let bad_regex = NO_ALERT.replace("^(alert|confirm)$", "(alert");
+        assert!(compile(&bad_regex).err().unwrap().contains("invalid regex"));
+    }
+}
@@ -2,21 +2,54 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
-use crate::rules::Rule;
-use crate::types::{Finding, Severity};
+use crate::parsers::{Language, SemanticNode, is_doc_comment};
+use crate::rules::{Rule, RuleContext, RuleDocs, RuleOption, parse_options};
+use crate::suppression::is_directive;
+use crate::types::{Category, Edit, Finding, Severity};
+use serde::Deserialize;
 use std::path::Path;
 
 /// JS/TS keywords that suggest a line is code rather than prose.
-const CODE_KEYWORDS: &[&str] = &[
+const JS_CODE_KEYWORDS: &[&str] = &[
     "function", "const", "let", "var", "if", "else", "for", "while", "return", "import", "export",
     "class", "switch", "case", "break", "continue", "throw", "try", "catch", "finally", "new",
     "async", "await",
 ];
 
+/// Python keywords that suggest a line is code rather than prose.
+const PYTHON_CODE_KEYWORDS: &[&str] = &[
+    "def", "class", "import", "from", "return", "if", "elif", "else", "for", "while", "try",
+    "except", "finally", "with", "raise", "pass", "yield", "lambda", "async", "await", "assert",
+    "del", "global", "nonlocal",
+];
+
+/// Rust keywords that suggest a line is code rather than prose.
+const RUST_CODE_KEYWORDS: &[&str] = &[
+    "let", "fn", "pub", "use", "mod", "struct", "enum", "impl", "trait", "match", "if", "else",
+    "for", "while", "loop", "return", "const", "static", "type", "unsafe", "async", "break",
+    "continue",
+];
+
 /// Minimum fraction of lines that must look like code to flag a multi-line comment group.
 const CODE_LINE_THRESHOLD: f64 = 0.6;
 
-pub struct CommentedOutCode;
+pub struct CommentedOutCode {
+    code_line_threshold: f64,
+}
+
+impl Default for CommentedOutCode {
+    fn default() -> Self {
+        Self {
+            code_line_threshold: CODE_LINE_THRESHOLD,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Options {
+    code_line_threshold: Option<f64>,
+}
 
 impl Rule for CommentedOutCode {
     fn id(&self) -> &'static str {
@@ -31,22 +64,60 @@ impl Rule for CommentedOutCode {
     fn severity(&self) -> Severity {
         Severity::Warn
     }
+    fn category(&self) -> Category {
+        Category::Slop
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: "Commented-out code rots: it is never compiled or tested, readers cannot tell \
+                whether it is meant to come back, and version control already preserves it.",
+            heuristic: "Consecutive `//` or `#` lines are grouped, and `/* */` blocks are treated \
+                as one group. A line looks like code when it ends in `;`, `{`, `}`, `)` or `]`, \
+                starts with a language keyword such as `const`, `def`, `let` or `return`, contains an \
+                assignment or arrow, or contains a method call. A group of two or more lines is \
+                flagged when at least `code_line_threshold` (60%) of its lines look like code. A \
+                lone JS/TS/Rust line is flagged only if it is a complete statement ending in `;`; a lone \
+                Python line only if it is an import, a plain assignment or a bare call. JSDoc, Rust \
+                doc comments, docstrings, SPDX headers, Python shebang and encoding lines on the \
+                first two lines, `@` annotations and patina directives are exempt.",
+            flagged_example: "// const total = items.reduce((a, b) => a + b, 0);\n// console.log(total);\nreturn items.length;",
+            not_flagged_example: "// Totals are computed lazily by the report view\nreturn items.length;",
+            options: &[RuleOption {
+                name: "code_line_threshold",
+                default: "0.6",
+                description: "Fraction of lines in a group that must look like code (0.0-1.0)",
+            }],
+            false_positives: &[
+                "Usage examples embedded in prose comments",
+                "ASCII diagrams or tables whose lines end in `)` or `}`",
+            ],
+        }
+    }
 
-    fn check(
-        &self,
-        source: &[u8],
-        tree: &tree_sitter::Tree,
-        file_path: &Path,
-    ) -> Vec<Finding> {
+    fn configure(&mut self, config: &toml::Value) -> Result<(), String> {
+        let options: Options = parse_options(config)?;
+        if let Some(threshold) = options.code_line_threshold {
+            if !(0.0..=1.0).contains(&threshold) {
+                return Err(format!(
+                    "code_line_threshold must be between 0.0 and 1.0, got {threshold}"
+                ));
+            }
+            self.code_line_threshold = threshold;
+        }
+        Ok(())
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
         let mut findings = Vec::new();
-        let source_str = match std::str::from_utf8(source) {
+        let source_str = match std::str::from_utf8(ctx.source) {
             Ok(s) => s,
             Err(_) => return findings,
         };
+        let (file_path, language) = (ctx.file_path, ctx.language);
 
-        let mut cursor = tree.walk();
+        let mut cursor = ctx.tree.walk();
         let mut comment_nodes = Vec::new();
-        Self::collect_comments(&mut cursor, &mut comment_nodes);
+        Self::collect_comments(ctx, &mut cursor, &mut comment_nodes);
 
         // Process groups of consecutive single-line comments
         let mut i = 0;
@@ -63,7 +134,7 @@ impl Rule for CommentedOutCode {
             // Handle block comments (/* ... */)
             if text.starts_with("/*") {
                 if !Self::is_exempt_block(text)
-                    && let Some(finding) = Self::check_block_comment(node, text, file_path)
+                    && let Some(finding) = self.check_block_comment(node, text, source_str, file_path, language)
                 {
                     findings.push(finding);
                 }
@@ -71,14 +142,17 @@ impl Rule for CommentedOutCode {
                 continue;
             }
 
-            // Skip exempt single-line comments (annotations, SPDX headers)
+            // Skip exempt single-line comments (doc comments, annotations, SPDX headers)
             let start_content = Self::strip_line_comment(text);
-            if Self::is_exempt_line(start_content) {
+            if is_doc_comment(text)
+                || Self::is_exempt_line(start_content)
+                || Self::is_python_header(node, start_content, language)
+            {
                 i += 1;
                 continue;
             }
 
-            // Gather consecutive single-line // comments, breaking on exempt lines
+            // Gather consecutive single-line comments, breaking on exempt lines
             let group_start = i;
             let mut group_end = i + 1;
             while group_end < comment_nodes.len() {
@@ -94,16 +168,19 @@ impl Rule for CommentedOutCode {
                     Err(_) => break,
                 };
 
-                // Both must be // comments on consecutive lines
-                if !prev_text.starts_with("//") || !curr_text.starts_with("//") {
+                // Both must be line comments on consecutive lines
+                if !Self::is_line_comment(prev_text) || !Self::is_line_comment(curr_text) {
                     break;
                 }
                 if curr.start_position().row != prev.start_position().row + 1 {
                     break;
                 }
-                // Don't include exempt lines (annotations, SPDX) in groups
+                // Don't include exempt lines (doc comments, annotations, SPDX) in groups
                 let curr_content = Self::strip_line_comment(curr_text);
-                if Self::is_exempt_line(curr_content) {
+                if is_doc_comment(curr_text)
+                    || Self::is_exempt_line(curr_content)
+                    || Self::is_python_header(curr, curr_content, language)
+                {
                     break;
                 }
                 group_end += 1;
@@ -113,14 +190,14 @@ impl Rule for CommentedOutCode {
             if group.len() == 1 {
                 // Single-line comment: check if it's a complete statement
                 if let Some(finding) =
-                    Self::check_single_line(group[0], source_str, file_path)
+                    Self::check_single_line(group[0], source_str, file_path, language)
                 {
                     findings.push(finding);
                 }
             } else {
                 // Multi-line group: check if ≥60% of lines look like code
                 if let Some(finding) =
-                    Self::check_comment_group(group, source_str, file_path)
+                    self.check_comment_group(group, source_str, file_path, language)
                 {
                     findings.push(finding);
                 }
@@ -135,12 +212,13 @@ impl Rule for CommentedOutCode {
 
 impl CommentedOutCode {
     fn collect_comments<'a>(
+        ctx: &RuleContext,
         cursor: &mut tree_sitter::TreeCursor<'a>,
         comments: &mut Vec<tree_sitter::Node<'a>>,
     ) {
         loop {
             let node = cursor.node();
-            if node.kind() == "comment" {
+            if ctx.is(node, SemanticNode::Comment) {
                 comments.push(node);
             }
 
@@ -161,13 +239,28 @@ impl CommentedOutCode {
         }
     }
 
+    fn is_line_comment(text: &str) -> bool {
+        text.starts_with("//") || text.starts_with('#')
+    }
+
     fn strip_line_comment(text: &str) -> &str {
-        text.strip_prefix("//").unwrap_or(text).trim()
+        text.strip_prefix("//")
+            .or_else(|| text.strip_prefix('#'))
+            .unwrap_or(text)
+            .trim()
+    }
+
+    fn keywords(language: Language) -> &'static [&'static str] {
+        match language {
+            Language::Python => PYTHON_CODE_KEYWORDS,
+            Language::Rust => RUST_CODE_KEYWORDS,
+            Language::JavaScript | Language::TypeScript | Language::Tsx => JS_CODE_KEYWORDS,
+        }
     }
 
     fn is_exempt_block(text: &str) -> bool {
-        // JSDoc blocks
-        if text.starts_with("/**") {
+        // JSDoc blocks and Rust doc comments
+        if is_doc_comment(text) {
             return true;
         }
         // SPDX headers
@@ -175,7 +268,16 @@ impl CommentedOutCode {
         if lower.contains("spdx-") {
             return true;
         }
-        false
+        // patina suppression directives
+        is_directive(text)
+    }
+
+    /// A Python shebang or encoding declaration, which only count as such on
+    /// the first two lines.
+    fn is_python_header(node: tree_sitter::Node, content: &str, language: Language) -> bool {
+        language == Language::Python
+            && node.start_position().row < 2
+            && (content.starts_with('!') || content.contains("-*-"))
     }
 
     fn is_exempt_line(line: &str) -> bool {
@@ -193,10 +295,11 @@ impl CommentedOutCode {
         if lower.starts_with("spdx-") {
             return true;
         }
-        false
+        // patina suppression directives
+        is_directive(trimmed)
     }
 
-    fn looks_like_code(line: &str) -> bool {
+    fn looks_like_code(line: &str, language: Language) -> bool {
         let trimmed = line.trim();
         if trimmed.is_empty() {
             return false;
@@ -212,17 +315,18 @@ impl CommentedOutCode {
             || trimmed.ends_with('{')
             || trimmed.ends_with('}')
             || trimmed.ends_with(')')
+            || trimmed.ends_with(']')
         {
             return true;
         }
 
-        // Lines starting with JS keywords
+        // Lines starting with language keywords
         let lower = trimmed.to_lowercase();
-        for kw in CODE_KEYWORDS {
+        for kw in Self::keywords(language) {
             if lower.starts_with(kw)
                 && trimmed
                     .get(kw.len()..kw.len() + 1)
-                    .is_some_and(|c| c == " " || c == "(" || c == "{")
+                    .is_some_and(|c| c == " " || c == "(" || c == "{" || c == ":")
             {
                 return true;
             }
@@ -246,9 +350,12 @@ impl CommentedOutCode {
     }
 
     fn check_block_comment(
+        &self,
         node: tree_sitter::Node,
         text: &str,
+        source: &str,
         file_path: &Path,
+        language: Language,
     ) -> Option<Finding> {
         let inner = text
             .strip_prefix("/*")?
@@ -265,10 +372,10 @@ impl CommentedOutCode {
             return None;
         }
 
-        let code_lines = lines.iter().filter(|l| Self::looks_like_code(l)).count();
+        let code_lines = lines.iter().filter(|l| Self::looks_like_code(l, language)).count();
         let ratio = code_lines as f64 / lines.len() as f64;
 
-        if ratio >= CODE_LINE_THRESHOLD {
+        if ratio >= self.code_line_threshold {
             let start = node.start_position();
             Some(Finding {
                 rule_id: "",
@@ -282,16 +389,48 @@ impl CommentedOutCode {
                     "Remove commented-out code — use version control to preserve old code."
                         .to_string(),
                 ),
+                fix: Some(Edit::delete_lines(source.as_bytes(), node.byte_range())),
+                metrics: None,
+                related: Vec::new(),
             })
         } else {
             None
         }
     }
 
+    /// Python statements have no terminator, so a lone line only counts when it
+    /// is an import, an assignment to a name or attribute, or a bare call such
+    /// as `print(x)`. Upper-case callees are skipped to leave `TODO(name)` alone.
+    fn is_python_statement(content: &str) -> bool {
+        let is_path = |s: &str| {
+            !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+        };
+
+        if content.starts_with("import ")
+            || (content.starts_with("from ") && content.contains(" import "))
+        {
+            return true;
+        }
+        if let Some((target, _)) = content.split_once(" = ")
+            && is_path(target)
+        {
+            return true;
+        }
+        if content.ends_with(')')
+            && let Some((callee, _)) = content.split_once('(')
+            && is_path(callee)
+            && callee != callee.to_uppercase()
+        {
+            return true;
+        }
+        false
+    }
+
     fn check_single_line(
         node: tree_sitter::Node,
         source: &str,
         file_path: &Path,
+        language: Language,
     ) -> Option<Finding> {
         let text = node.utf8_text(source.as_bytes()).ok()?;
         let content = Self::strip_line_comment(text);
@@ -301,12 +440,17 @@ impl CommentedOutCode {
         }
 
         // Only flag if it looks like a complete statement
-        let is_statement = content.ends_with(';')
-            && (content.contains('(')
-                || content.contains('=')
-                || CODE_KEYWORDS
-                    .iter()
-                    .any(|kw| content.to_lowercase().starts_with(kw)));
+        let is_statement = match language {
+            Language::Python => Self::is_python_statement(content),
+            Language::JavaScript | Language::TypeScript | Language::Tsx | Language::Rust => {
+                content.ends_with(';')
+                    && (content.contains('(')
+                        || content.contains('=')
+                        || Self::keywords(language)
+                            .iter()
+                            .any(|kw| content.to_lowercase().starts_with(kw)))
+            }
+        };
 
         if is_statement {
             let start = node.start_position();
@@ -322,6 +466,9 @@ impl CommentedOutCode {
                     "Remove commented-out code — use version control to preserve old code."
                         .to_string(),
                 ),
+                fix: Some(Edit::delete_lines(source.as_bytes(), node.byte_range())),
+                metrics: None,
+                related: Vec::new(),
             })
         } else {
             None
@@ -329,9 +476,11 @@ impl CommentedOutCode {
     }
 
     fn check_comment_group(
+        &self,
         group: &[tree_sitter::Node],
         source: &str,
         file_path: &Path,
+        language: Language,
     ) -> Option<Finding> {
         let lines: Vec<&str> = group
             .iter()
@@ -346,10 +495,10 @@ impl CommentedOutCode {
             return None;
         }
 
-        let code_lines = non_empty.iter().filter(|l| Self::looks_like_code(l)).count();
+        let code_lines = non_empty.iter().filter(|l| Self::looks_like_code(l, language)).count();
         let ratio = code_lines as f64 / non_empty.len() as f64;
 
-        if ratio >= CODE_LINE_THRESHOLD {
+        if ratio >= self.code_line_threshold {
             let first = group[0];
             let last = group[group.len() - 1];
             let start = first.start_position();
@@ -365,6 +514,12 @@ impl CommentedOutCode {
                     "Remove commented-out code — use version control to preserve old code."
                         .to_string(),
                 ),
+                fix: Some(Edit::delete_lines(
+                    source.as_bytes(),
+                    first.start_byte()..last.end_byte(),
+                )),
+                metrics: None,
+                related: Vec::new(),
             })
         } else {
             None
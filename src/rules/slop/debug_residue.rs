@@ -0,0 +1,227 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::parsers::{Language, SemanticNode};
+use crate::rules::{Rule, RuleContext, RuleDocs, RuleOption, parse_options};
+use crate::types::{Category, Edit, Finding, Severity};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// Files where console output is the point: CLIs, scripts and tests.
+const ALLOWED_PATHS: &[&str] = &[
+    "bin/**",
+    "scripts/**",
+    "cli.*",
+    "cli/**",
+    "test/**",
+    "tests/**",
+    "__tests__/**",
+    "*.test.*",
+    "*.spec.*",
+];
+
+/// `console` methods that report real problems rather than debug output.
+const ALLOWED_METHODS: &[&str] = &["error", "warn"];
+
+pub struct DebugResidue {
+    allowed_paths: GlobSet,
+    allowed_methods: Vec<String>,
+}
+
+impl Default for DebugResidue {
+    fn default() -> Self {
+        Self {
+            allowed_paths: build_globs(ALLOWED_PATHS).expect("default globs are valid"),
+            allowed_methods: ALLOWED_METHODS.iter().map(|m| m.to_string()).collect(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Options {
+    allowed_paths: Option<Vec<String>>,
+    allowed_methods: Option<Vec<String>>,
+}
+
+impl Rule for DebugResidue {
+    fn id(&self) -> &'static str {
+        "slop-007"
+    }
+    fn name(&self) -> &'static str {
+        "Debug Residue"
+    }
+    fn description(&self) -> &'static str {
+        "Detects leftover console logging, debugger statements and alert() calls"
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warn
+    }
+    fn category(&self) -> Category {
+        Category::Slop
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: "`console.log`, `debugger;` and `alert()` are debugging tools. Left in \
+                application code they leak internal state to the browser console, pause execution \
+                for anyone with devtools open, or interrupt users with a modal.",
+            heuristic: "In JavaScript and TypeScript, calls to `console.<method>` are flagged unless the method is in \
+                `allowed_methods` (`error`, `warn`), as are `debugger` statements and calls to \
+                `alert()` / `window.alert()`. Files matching `allowed_paths` (CLIs, scripts and \
+                tests by default) are skipped; each glob is matched against the end of the file's \
+                path, so `scripts/**` covers every `scripts` directory. A `debugger` statement, or \
+                a call that is a whole statement, directly inside a block or at the top level gets \
+                a fix that deletes the statement; the unbraced body of an `if` or loop is left for a human.",
+            flagged_example: "function total(items) {\n  console.log(items);\n  return items.length;\n}",
+            not_flagged_example: "function total(items) {\n  if (!items) console.error(\"total: no items\");\n  return items.length;\n}",
+            options: &[
+                RuleOption {
+                    name: "allowed_paths",
+                    default: "[\"bin/**\", \"scripts/**\", \"cli.*\", \"cli/**\", \"test/**\", \"tests/**\", \"__tests__/**\", \"*.test.*\", \"*.spec.*\"]",
+                    description: "Globs for files where console output is legitimate",
+                },
+                RuleOption {
+                    name: "allowed_methods",
+                    default: "[\"error\", \"warn\"]",
+                    description: "`console` methods that are not debug output",
+                },
+            ],
+            false_positives: &[
+                "Libraries whose documented behaviour is to log through `console`",
+                "Command-line tools outside the default `allowed_paths`",
+            ],
+        }
+    }
+
+    fn configure(&mut self, config: &toml::Value) -> Result<(), String> {
+        let options: Options = parse_options(config)?;
+        if let Some(paths) = options.allowed_paths {
+            self.allowed_paths = build_globs(&paths)?;
+        }
+        if let Some(methods) = options.allowed_methods {
+            self.allowed_methods = methods;
+        }
+        Ok(())
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        let is_js = matches!(ctx.language, Language::JavaScript | Language::TypeScript | Language::Tsx);
+        if !is_js || self.is_allowed_path(ctx.file_path) {
+            return findings;
+        }
+
+        let mut cursor = ctx.tree.walk();
+        self.walk_tree(ctx, &mut cursor, &mut findings);
+        findings
+    }
+}
+
+impl DebugResidue {
+    fn walk_tree(&self, ctx: &RuleContext, cursor: &mut tree_sitter::TreeCursor, findings: &mut Vec<Finding>) {
+        loop {
+            let node = cursor.node();
+
+            if ctx.is(node, SemanticNode::DebuggerStatement) {
+                findings.push(Self::finding(ctx, node, "`debugger` statement".to_string(), Self::deletable(ctx, node)));
+            } else if ctx.is(node, SemanticNode::CallExpression)
+                && let Some(what) = self.residue_call(ctx, node)
+            {
+                findings.push(Self::finding(ctx, node, what, Self::enclosing_statement(ctx, node)));
+            }
+
+            if cursor.goto_first_child() {
+                continue;
+            }
+            if cursor.goto_next_sibling() {
+                continue;
+            }
+            loop {
+                if !cursor.goto_parent() {
+                    return;
+                }
+                if cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Describes the call if it is debug output: `console.log` or `alert`.
+    fn residue_call(&self, ctx: &RuleContext, call: tree_sitter::Node) -> Option<String> {
+        let callee = call.child_by_field_name("function")?.utf8_text(ctx.source).ok()?;
+        let callee = callee.strip_prefix("window.").unwrap_or(callee);
+        if callee == "alert" {
+            return Some("`alert()` call".to_string());
+        }
+        let method = callee.strip_prefix("console.")?;
+        if self.allowed_methods.iter().any(|allowed| allowed == method) {
+            return None;
+        }
+        Some(format!("`console.{method}()` call"))
+    }
+
+    /// The statement consisting of just `call`, which can be deleted whole.
+    fn enclosing_statement<'t>(ctx: &RuleContext, call: tree_sitter::Node<'t>) -> Option<tree_sitter::Node<'t>> {
+        let parent = Self::deletable(ctx, call.parent()?)?;
+        let statement = parent.utf8_text(ctx.source).ok()?;
+        let expression = call.utf8_text(ctx.source).ok()?;
+        (statement.trim_end_matches(';').trim_end() == expression).then_some(parent)
+    }
+
+    /// `statement`, if it sits in a block or at the top level. Deleting the
+    /// unbraced body of an `if` or loop would make the next statement its body.
+    fn deletable<'t>(ctx: &RuleContext, statement: tree_sitter::Node<'t>) -> Option<tree_sitter::Node<'t>> {
+        let container = statement.parent()?;
+        (ctx.is(container, SemanticNode::Block) || container.parent().is_none()).then_some(statement)
+    }
+
+    fn is_allowed_path(&self, path: &Path) -> bool {
+        let components: Vec<_> = path.components().collect();
+        (0..components.len()).any(|i| {
+            let suffix: PathBuf = components[i..].iter().collect();
+            self.allowed_paths.is_match(&suffix)
+        })
+    }
+
+    fn finding(
+        ctx: &RuleContext,
+        node: tree_sitter::Node,
+        what: String,
+        statement: Option<tree_sitter::Node>,
+    ) -> Finding {
+        let start = node.start_position();
+        Finding {
+            rule_id: "",
+            message: format!("leftover {what}"),
+            severity: Severity::Warn,
+            file: ctx.file_path.to_path_buf(),
+            line: start.row + 1,
+            column: start.column + 1,
+            span: node.byte_range(),
+            suggestion: Some(
+                "Remove the debugging code, or report through the application's logger.".to_string(),
+            ),
+            fix: statement.map(|statement| Edit::delete_lines(ctx.source, statement.byte_range())),
+            metrics: None,
+            related: Vec::new(),
+        }
+    }
+}
+
+/// Compiles `patterns` with `*` stopping at `/`, so `*.test.*` only matches a
+/// file name.
+fn build_globs(patterns: &[impl AsRef<str>]) -> Result<GlobSet, String> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let pattern = pattern.as_ref();
+        let glob = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .map_err(|e| format!("invalid glob `{pattern}` in allowed_paths: {e}"))?;
+        builder.add(glob);
+    }
+    builder.build().map_err(|e| e.to_string())
+}
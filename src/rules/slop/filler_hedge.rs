@@ -2,8 +2,12 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
-use crate::rules::Rule;
-use crate::types::{Finding, Severity};
+use crate::parsers::{SemanticNode, is_doc_comment};
+use crate::rules::{Rule, RuleContext, RuleDocs, RuleOption, parse_options};
+use crate::suppression::is_directive;
+use crate::tokens::comment_body;
+use crate::types::{Category, Finding, Severity};
+use serde::Deserialize;
 use std::path::Path;
 
 /// Phrase-start patterns that indicate filler/hedge language in comments.
@@ -41,7 +45,23 @@ const FILLER_WORDS: &[&str] = &[
 
 const FILLER_DENSITY_THRESHOLD: usize = 3;
 
-pub struct FillerHedge;
+pub struct FillerHedge {
+    density_threshold: usize,
+}
+
+impl Default for FillerHedge {
+    fn default() -> Self {
+        Self {
+            density_threshold: FILLER_DENSITY_THRESHOLD,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Options {
+    density_threshold: Option<usize>,
+}
 
 impl Rule for FillerHedge {
     fn id(&self) -> &'static str {
@@ -56,37 +76,69 @@ impl Rule for FillerHedge {
     fn severity(&self) -> Severity {
         Severity::Warn
     }
+    fn category(&self) -> Category {
+        Category::Slop
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: "Filler and hedge words (\"basically\", \"simply\", \"just\", \"it's worth \
+                noting\") pad comments without adding information and make the writer sound unsure \
+                of the code. A direct statement is shorter and clearer.",
+            heuristic: "A comment line is flagged if it starts with a filler phrase (\"basically,\", \
+                \"just \", \"note:\", \"important:\", \"please note\", ...) or contains at least \
+                `density_threshold` (3) filler words such as \"really\", \"very\", \"quite\", \
+                \"probably\". \"Note:\" followed by a reference (RFC, URL, issue number) and \
+                \"just-in-time\"/\"just as\" are exempt. JSDoc blocks are skipped.",
+            flagged_example: "// Basically, this just retries the request\nretry(request);",
+            not_flagged_example: "// Retry once: the upstream drops the first request after a deploy\nretry(request);",
+            options: &[RuleOption {
+                name: "density_threshold",
+                default: "3",
+                description: "Number of filler words on one line that triggers a finding",
+            }],
+            false_positives: &[
+                "\"Note:\" or \"Important:\" introducing a genuine warning without a reference",
+            ],
+        }
+    }
 
-    fn check(
-        &self,
-        source: &[u8],
-        tree: &tree_sitter::Tree,
-        file_path: &Path,
-    ) -> Vec<Finding> {
+    fn configure(&mut self, config: &toml::Value) -> Result<(), String> {
+        let options: Options = parse_options(config)?;
+        if let Some(density_threshold) = options.density_threshold {
+            if density_threshold == 0 {
+                return Err("density_threshold must be at least 1".to_string());
+            }
+            self.density_threshold = density_threshold;
+        }
+        Ok(())
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
         let mut findings = Vec::new();
-        let source_str = match std::str::from_utf8(source) {
+        let source_str = match std::str::from_utf8(ctx.source) {
             Ok(s) => s,
             Err(_) => return findings,
         };
 
-        let mut cursor = tree.walk();
-        Self::walk_tree(&mut cursor, source_str, file_path, &mut findings);
+        let mut cursor = ctx.tree.walk();
+        self.walk_tree(ctx, &mut cursor, source_str, &mut findings);
         findings
     }
 }
 
 impl FillerHedge {
     fn walk_tree(
+        &self,
+        ctx: &RuleContext,
         cursor: &mut tree_sitter::TreeCursor,
         source: &str,
-        file_path: &Path,
         findings: &mut Vec<Finding>,
     ) {
         loop {
             let node = cursor.node();
 
-            if node.kind() == "comment"
-                && let Some(finding) = Self::check_comment(node, source, file_path)
+            if ctx.is(node, SemanticNode::Comment)
+                && let Some(finding) = self.check_comment(node, source, ctx.file_path)
             {
                 findings.push(finding);
             }
@@ -109,26 +161,20 @@ impl FillerHedge {
     }
 
     fn check_comment(
+        &self,
         node: tree_sitter::Node,
         source: &str,
         file_path: &Path,
     ) -> Option<Finding> {
         let text = node.utf8_text(source.as_bytes()).ok()?;
 
-        // Skip JSDoc blocks
-        if text.starts_with("/**") {
+        // Skip JSDoc blocks, Rust doc comments and suppression directives
+        if is_doc_comment(text) || is_directive(text) {
             return None;
         }
 
         // Strip comment markers and check each line
-        let cleaned = text
-            .trim()
-            .strip_prefix("//")
-            .or_else(|| text.trim().strip_prefix("/*"))
-            .unwrap_or(text.trim());
-        let cleaned = cleaned.strip_suffix("*/").unwrap_or(cleaned);
-
-        for line in cleaned.lines() {
+        for line in comment_body(text).lines() {
             let line = line.trim().strip_prefix('*').unwrap_or(line.trim()).trim();
             let lower = line.to_lowercase();
 
@@ -160,6 +206,9 @@ impl FillerHedge {
                         suggestion: Some(
                             "Remove filler words — state the point directly.".to_string(),
                         ),
+                        fix: None,
+                        metrics: None,
+                        related: Vec::new(),
                     });
                 }
             }
@@ -172,7 +221,7 @@ impl FillerHedge {
                     FILLER_WORDS.contains(&w)
                 })
                 .count();
-            if word_count >= FILLER_DENSITY_THRESHOLD {
+            if word_count >= self.density_threshold {
                 let start = node.start_position();
                 return Some(Finding {
                     rule_id: "",
@@ -185,6 +234,9 @@ impl FillerHedge {
                     suggestion: Some(
                         "Remove filler words — state the point directly.".to_string(),
                     ),
+                    fix: None,
+                    metrics: None,
+                    related: Vec::new(),
                 });
             }
         }
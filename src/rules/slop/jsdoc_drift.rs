@@ -0,0 +1,285 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::parsers::SemanticNode;
+use crate::rules::jsdoc::{self, DocLine, JsDoc, Parameter, Tag};
+use crate::rules::{Rule, RuleContext, RuleDocs};
+use crate::types::{Category, Finding, Severity};
+
+/// `@returns` types that promise nothing, so a missing `return` is fine.
+const NOTHING_TYPES: &[&str] = &["void", "undefined", "never", "Promise<void>", "Promise<undefined>"];
+
+pub struct JsDocDrift;
+
+impl Rule for JsDocDrift {
+    fn id(&self) -> &'static str {
+        "slop-009"
+    }
+    fn name(&self) -> &'static str {
+        "JSDoc Drift"
+    }
+    fn description(&self) -> &'static str {
+        "Detects JSDoc tags that no longer match the function signature"
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warn
+    }
+    fn category(&self) -> Category {
+        Category::Slop
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: "A refactor that renames, adds or reorders parameters but leaves the JSDoc \
+                alone produces documentation that is confidently wrong. Editors show it in \
+                tooltips and type checkers read it, so stale tags mislead more than missing ones.",
+            heuristic: "Each `/** */` block is paired with the function, method or arrow function \
+                that follows it. When the block has at least one `@param` tag, its top-level \
+                `@param` names are compared with the formal parameters, looking through default \
+                values, `...rest` and TypeScript's `?`. A destructured parameter matches the tag \
+                at its position, and `@param options.key` tags are checked against the keys it \
+                takes apart. Separate findings report a tag for a parameter that does not exist, \
+                a parameter with no tag, and a tag out of signature order. A `@returns` with a \
+                non-void type on a function that never returns a value, and a `@throws` on a \
+                function that neither throws nor calls anything, are also reported.",
+            flagged_example: "/**\n * Sends a message to a user.\n * @param {string} userId - Recipient\n * @param {string} text - Message body\n */\nfunction send(recipientId, text) {\n  queue.push({ recipientId, text });\n}",
+            not_flagged_example: "/**\n * Sends a message to a user.\n * @param {string} recipientId - Recipient\n * @param {string} text - Message body\n */\nfunction send(recipientId, text) {\n  queue.push({ recipientId, text });\n}",
+            options: &[],
+            false_positives: &[
+                "Functions that read `arguments` instead of naming their parameters",
+                "`@throws` on a function that throws through `new` of a validating constructor",
+            ],
+        }
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        let mut cursor = ctx.tree.walk();
+        Self::walk_tree(ctx, &mut cursor, &mut findings);
+        findings
+    }
+}
+
+impl JsDocDrift {
+    fn walk_tree(ctx: &RuleContext, cursor: &mut tree_sitter::TreeCursor, findings: &mut Vec<Finding>) {
+        loop {
+            let node = cursor.node();
+
+            if ctx.is(node, SemanticNode::Comment)
+                && let Some(doc) = jsdoc::parse(node, ctx.source)
+                && let Some(function) = jsdoc::documented_function(ctx, node)
+            {
+                Self::check_params(ctx, &doc, function, findings);
+                Self::check_returns(ctx, &doc, function, findings);
+                Self::check_throws(ctx, &doc, function, findings);
+            }
+
+            if cursor.goto_first_child() {
+                continue;
+            }
+            if cursor.goto_next_sibling() {
+                continue;
+            }
+            loop {
+                if !cursor.goto_parent() {
+                    return;
+                }
+                if cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn check_params(ctx: &RuleContext, doc: &JsDoc, function: tree_sitter::Node, findings: &mut Vec<Finding>) {
+        let tags: Vec<(&Tag, &str)> = doc.params().filter_map(|tag| Some((tag, tag.param?))).collect();
+        if tags.is_empty() {
+            return;
+        }
+        let params = jsdoc::parameters(ctx, function);
+        let (top, nested): (Vec<_>, Vec<_>) = tags.into_iter().partition(|(_, name)| !name.contains('.'));
+
+        // Match tags to parameters by name, then destructured parameters by position
+        let mut matched: Vec<Option<usize>> = top
+            .iter()
+            .map(|(_, name)| params.iter().position(|p| p.name == Some(*name)))
+            .collect();
+        for (index, param) in params.iter().enumerate() {
+            if param.name.is_none()
+                && let Some(slot @ None) = matched.get_mut(index)
+            {
+                *slot = Some(index);
+            }
+        }
+
+        let mut furthest: Option<usize> = None;
+        for ((tag, name), param) in top.iter().zip(&matched) {
+            match *param {
+                None => findings.push(Self::tag_finding(
+                    tag,
+                    name,
+                    ctx,
+                    format!("`@{} {name}` documents a parameter that does not exist", tag.name),
+                )),
+                Some(index) => {
+                    if let Some(previous) = furthest
+                        && index < previous
+                    {
+                        findings.push(Self::tag_finding(
+                            tag,
+                            name,
+                            ctx,
+                            format!(
+                                "`@{} {name}` is out of order: it is parameter {} of the signature but \
+                                 documented after parameter {}",
+                                tag.name,
+                                index + 1,
+                                previous + 1
+                            ),
+                        ));
+                    }
+                    furthest = furthest.max(Some(index));
+                }
+            }
+        }
+
+        for (tag, name) in &nested {
+            let (root, rest) = name.split_once('.').unwrap_or((name, ""));
+            let root = root.trim_end_matches("[]");
+            let key = rest.split('.').next().unwrap_or(rest).trim_end_matches("[]");
+            let param = match top.iter().zip(&matched).find(|((_, top_name), _)| *top_name == root) {
+                // An unknown root is reported once, on its own tag
+                Some((_, None)) => continue,
+                Some((_, Some(index))) => Some(*index),
+                None => params.iter().position(|p| p.name == Some(root)),
+            };
+            match param.map(|index| &params[index]) {
+                None => findings.push(Self::tag_finding(
+                    tag,
+                    name,
+                    ctx,
+                    format!("`@{} {name}` documents a property of `{root}`, which is not a parameter", tag.name),
+                )),
+                Some(Parameter { keys: Some(keys), .. }) if !keys.contains(&key) => {
+                    findings.push(Self::tag_finding(
+                        tag,
+                        name,
+                        ctx,
+                        format!(
+                            "`@{} {name}` documents a property `{key}` that the parameter does not destructure",
+                            tag.name
+                        ),
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+
+        for (index, param) in params.iter().enumerate() {
+            let documented = matched.contains(&Some(index))
+                || param
+                    .name
+                    .is_some_and(|name| nested.iter().any(|(_, n)| n.split(['.', '[']).next() == Some(name)));
+            if documented {
+                continue;
+            }
+            let what = match param.name {
+                Some(name) => format!("parameter `{name}`"),
+                None => format!("destructured parameter {}", index + 1),
+            };
+            let start = param.node.start_position();
+            findings.push(Self::finding(
+                ctx,
+                start.row,
+                start.column,
+                param.node.byte_range(),
+                format!("{what} has no `@param` tag"),
+            ));
+        }
+    }
+
+    fn check_returns(ctx: &RuleContext, doc: &JsDoc, function: tree_sitter::Node, findings: &mut Vec<Finding>) {
+        let Some(tag) = doc.returns() else {
+            return;
+        };
+        let Some(type_expr) = tag.type_expr else {
+            return;
+        };
+        if NOTHING_TYPES.contains(&type_expr)
+            || function.child_by_field_name("body").is_none()
+            || is_generator(ctx, function)
+            || jsdoc::returns_value(ctx, function)
+        {
+            return;
+        }
+        findings.push(Self::tag_finding(
+            tag,
+            type_expr,
+            ctx,
+            format!("`@{} {{{type_expr}}}` documents a value, but the function never returns one", tag.name),
+        ));
+    }
+
+    fn check_throws(ctx: &RuleContext, doc: &JsDoc, function: tree_sitter::Node, findings: &mut Vec<Finding>) {
+        let Some(body) = function.child_by_field_name("body") else {
+            return;
+        };
+        let Some(tag) = doc.tags.iter().find(|tag| tag.name == "throws" || tag.name == "exception") else {
+            return;
+        };
+        if can_throw(ctx, body) {
+            return;
+        }
+        findings.push(Self::tag_finding(
+            tag,
+            tag.name,
+            ctx,
+            format!("`@{}` on a function that neither throws nor calls anything", tag.name),
+        ));
+    }
+
+    /// A finding on `part` of the tag's line: the parameter name or type.
+    fn tag_finding(tag: &Tag, part: &str, ctx: &RuleContext, message: String) -> Finding {
+        let DocLine { span, row, column, .. } = tag.line.sub(part);
+        Self::finding(ctx, row, column, span, message)
+    }
+
+    fn finding(
+        ctx: &RuleContext,
+        row: usize,
+        column: usize,
+        span: std::ops::Range<usize>,
+        message: String,
+    ) -> Finding {
+        Finding {
+            rule_id: "",
+            message,
+            severity: Severity::Warn,
+            file: ctx.file_path.to_path_buf(),
+            line: row + 1,
+            column: column + 1,
+            span,
+            suggestion: Some("Update the JSDoc to match the signature.".to_string()),
+            fix: None,
+            metrics: None,
+            related: Vec::new(),
+        }
+    }
+}
+
+/// `function*`, `*method()` and `async *method()`: generators yield rather
+/// than return.
+fn is_generator(ctx: &RuleContext, function: tree_sitter::Node) -> bool {
+    let Some(parameters) = function.child_by_field_name("parameters") else {
+        return false;
+    };
+    ctx.source[function.start_byte()..parameters.start_byte()].contains(&b'*')
+}
+
+fn can_throw(ctx: &RuleContext, node: tree_sitter::Node) -> bool {
+    if ctx.is(node, SemanticNode::Throw) || ctx.is(node, SemanticNode::CallExpression) {
+        return true;
+    }
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor).any(|child| can_throw(ctx, child))
+}
@@ -3,7 +3,11 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
 pub mod commented_out_code;
+pub mod debug_residue;
 pub mod filler_hedge;
+pub mod jsdoc_drift;
+pub mod over_documentation;
 pub mod reasoning_artifact;
 pub mod redundant_comment;
+pub mod section_banner;
 pub mod self_narrating;
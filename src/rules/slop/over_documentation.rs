@@ -0,0 +1,202 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::parsers::SemanticNode;
+use crate::rules::jsdoc::{self, DocLine, JsDoc};
+use crate::rules::{Rule, RuleContext, RuleDocs};
+use crate::tokens::{extract_code_tokens, extract_comment_tokens, stem_word};
+use crate::types::{Category, Finding, Severity};
+
+/// Words that pad a description without saying anything about the value:
+/// "The given name", "Returns the name value".
+const BOILERPLATE_WORDS: &[&str] = &[
+    "function", "method", "returns", "parameter", "param", "argument", "value", "given",
+    "specified", "provided", "helper",
+];
+
+/// `@returns` types that say the function returns nothing.
+const NOTHING_TYPES: &[&str] = &["void", "undefined"];
+
+pub struct OverDocumentation;
+
+impl Rule for OverDocumentation {
+    fn id(&self) -> &'static str {
+        "slop-008"
+    }
+    fn name(&self) -> &'static str {
+        "Over-Documentation"
+    }
+    fn description(&self) -> &'static str {
+        "Detects JSDoc that only repeats what the function signature already says"
+    }
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+    fn category(&self) -> Category {
+        Category::Slop
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: "Boilerplate JSDoc such as `@param name - The name` doubles the length of a \
+                function without telling the reader anything the signature does not. It also \
+                buries the documentation that matters: units, ranges, side effects and errors.",
+            heuristic: "Each `/** */` block is paired with the function, method or arrow function \
+                that follows it, including through `export` and `const name = ...`. A finding is \
+                reported for a `@param` whose description, after stemming and dropping stop words \
+                and filler (\"given\", \"value\", ...), only contains words from the parameter's \
+                name; a summary that only restates the function's name; `@returns {void}` on a \
+                function that never returns a value; and a description of two or more paragraphs \
+                on a getter whose body is a single `return`.",
+            flagged_example: "/**\n * Sets the user name.\n * @param {string} userName - The user name\n */\nfunction setUserName(userName) {\n  this.userName = userName.trim();\n}",
+            not_flagged_example: "/**\n * Stores the display name shown in the header.\n * @param {string} userName - Surrounding whitespace is trimmed\n */\nfunction setUserName(userName) {\n  this.userName = userName.trim();\n}",
+            options: &[],
+            false_positives: &[
+                "Projects whose lint setup requires a description on every `@param`",
+                "Public APIs where generated reference docs need a summary for every entry",
+            ],
+        }
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        let mut cursor = ctx.tree.walk();
+        Self::walk_tree(ctx, &mut cursor, &mut findings);
+        findings
+    }
+}
+
+impl OverDocumentation {
+    fn walk_tree(ctx: &RuleContext, cursor: &mut tree_sitter::TreeCursor, findings: &mut Vec<Finding>) {
+        loop {
+            let node = cursor.node();
+
+            if ctx.is(node, SemanticNode::Comment)
+                && let Some(doc) = jsdoc::parse(node, ctx.source)
+                && let Some(function) = jsdoc::documented_function(ctx, node)
+            {
+                Self::check_doc(ctx, &doc, function, findings);
+            }
+
+            if cursor.goto_first_child() {
+                continue;
+            }
+            if cursor.goto_next_sibling() {
+                continue;
+            }
+            loop {
+                if !cursor.goto_parent() {
+                    return;
+                }
+                if cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn check_doc(ctx: &RuleContext, doc: &JsDoc, function: tree_sitter::Node, findings: &mut Vec<Finding>) {
+        if let Some(name) = ctx.function_name(function)
+            && doc.paragraphs() == 1
+            && let Some(summary) = doc.description.first()
+            && restates(summary.text, name)
+        {
+            findings.push(Self::finding(
+                ctx,
+                summary,
+                format!("JSDoc summary only restates the function name `{name}`"),
+            ));
+        }
+
+        for tag in doc.params() {
+            if let Some(param) = tag.param
+                && restates(&tag.description, param.rsplit('.').next().unwrap_or(param))
+            {
+                findings.push(Self::finding(
+                    ctx,
+                    &tag.line,
+                    format!("`@{} {param}` only restates the parameter name", tag.name),
+                ));
+            }
+        }
+
+        if let Some(tag) = doc.returns()
+            && let Some(type_expr) = tag.type_expr
+            && NOTHING_TYPES.contains(&type_expr)
+            && !jsdoc::returns_value(ctx, function)
+        {
+            findings.push(Self::finding(
+                ctx,
+                &tag.line,
+                format!("`@{} {{{type_expr}}}` on a function that returns nothing", tag.name),
+            ));
+        }
+
+        let paragraphs = doc.paragraphs();
+        if paragraphs >= 2
+            && Self::is_trivial_getter(ctx, function)
+            && let Some(first) = doc.description.first()
+        {
+            findings.push(Self::finding(
+                ctx,
+                first,
+                format!("{paragraphs}-paragraph JSDoc on a one-line getter"),
+            ));
+        }
+    }
+
+    /// A function with no parameters whose body is a single `return` of a
+    /// value, or an arrow function with an expression body.
+    fn is_trivial_getter(ctx: &RuleContext, function: tree_sitter::Node) -> bool {
+        let has_parameters = function
+            .child_by_field_name("parameters")
+            .is_some_and(|p| p.named_child_count() > 0)
+            || function.child_by_field_name("parameter").is_some();
+        let Some(body) = function.child_by_field_name("body") else {
+            return false;
+        };
+        if has_parameters {
+            return false;
+        }
+        if !ctx.is(body, SemanticNode::Block) {
+            return true;
+        }
+        let mut cursor = body.walk();
+        let statements: Vec<_> = body
+            .named_children(&mut cursor)
+            .filter(|n| !ctx.is(*n, SemanticNode::Comment))
+            .collect();
+        matches!(statements.as_slice(), [only] if ctx.is(*only, SemanticNode::Return) && only.named_child_count() > 0)
+    }
+
+    fn finding(ctx: &RuleContext, line: &DocLine, message: String) -> Finding {
+        Finding {
+            rule_id: "",
+            message,
+            severity: Severity::Info,
+            file: ctx.file_path.to_path_buf(),
+            line: line.row + 1,
+            column: line.column + 1,
+            span: line.span.clone(),
+            suggestion: Some(
+                "Delete it, or document what the signature cannot say: units, ranges, side effects, errors."
+                    .to_string(),
+            ),
+            fix: None,
+            metrics: None,
+            related: Vec::new(),
+        }
+    }
+}
+
+/// True if `description` has words and every one of them, apart from stop
+/// words and boilerplate, is a word of `identifier`.
+fn restates(description: &str, identifier: &str) -> bool {
+    let boilerplate: Vec<String> = BOILERPLATE_WORDS.iter().map(|w| stem_word(w)).collect();
+    let identifier_words = extract_code_tokens(&[identifier]);
+    let words: Vec<String> = extract_comment_tokens(description)
+        .into_iter()
+        .filter(|w| !boilerplate.contains(w))
+        .collect();
+    !words.is_empty() && words.iter().all(|w| identifier_words.contains(w))
+}
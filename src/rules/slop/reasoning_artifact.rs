@@ -2,8 +2,11 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
-use crate::rules::Rule;
-use crate::types::{Finding, Severity};
+use crate::parsers::SemanticNode;
+use crate::rules::{Rule, RuleContext, RuleDocs};
+use crate::suppression::is_directive;
+use crate::tokens::comment_body;
+use crate::types::{Category, Edit, Finding, Severity};
 use std::path::Path;
 
 /// Multi-word patterns that indicate AI reasoning traces in comments.
@@ -45,37 +48,51 @@ impl Rule for ReasoningArtifact {
     fn severity(&self) -> Severity {
         Severity::Warn
     }
+    fn category(&self) -> Category {
+        Category::Slop
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: "Chain-of-thought fragments such as \"Wait, that's wrong\" or \"Let me think\" \
+                are the model talking to itself. They describe a conversation that no longer exists \
+                and confuse readers about whether the code is settled.",
+            heuristic: "Each line of a comment or Python docstring is checked for a fixed list of \
+                reasoning openers (\"wait,\", \"actually,\", \"hmm,\", \"let me think\", \"on second \
+                thought\", \"i'm not sure\", ...) at the start of the line, case-insensitively.",
+            flagged_example: "// Wait, this should use the cached value instead\nconst value = compute(key);",
+            not_flagged_example: "// Use the cached value: compute() hits the network\nconst value = cache.get(key);",
+            options: &[],
+            false_positives: &[
+                "Comments that legitimately open with \"Actually,\" to contrast documented behaviour",
+            ],
+        }
+    }
 
-    fn check(
-        &self,
-        source: &[u8],
-        tree: &tree_sitter::Tree,
-        file_path: &Path,
-    ) -> Vec<Finding> {
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
         let mut findings = Vec::new();
-        let source_str = match std::str::from_utf8(source) {
+        let source_str = match std::str::from_utf8(ctx.source) {
             Ok(s) => s,
             Err(_) => return findings,
         };
 
-        let mut cursor = tree.walk();
-        Self::walk_tree(&mut cursor, source_str, file_path, &mut findings);
+        let mut cursor = ctx.tree.walk();
+        Self::walk_tree(ctx, &mut cursor, source_str, &mut findings);
         findings
     }
 }
 
 impl ReasoningArtifact {
     fn walk_tree(
+        ctx: &RuleContext,
         cursor: &mut tree_sitter::TreeCursor,
         source: &str,
-        file_path: &Path,
         findings: &mut Vec<Finding>,
     ) {
         loop {
             let node = cursor.node();
 
-            if node.kind() == "comment"
-                && let Some(finding) = Self::check_comment(node, source, file_path) {
+            if (ctx.is(node, SemanticNode::Comment) || ctx.is_docstring(node))
+                && let Some(finding) = Self::check_comment(node, source, ctx.file_path) {
                     findings.push(finding);
                 }
 
@@ -103,15 +120,12 @@ impl ReasoningArtifact {
     ) -> Option<Finding> {
         let text = node.utf8_text(source.as_bytes()).ok()?;
 
-        // Strip comment markers and check each line
-        let cleaned = text
-            .trim()
-            .strip_prefix("//")
-            .or_else(|| text.trim().strip_prefix("/*"))
-            .unwrap_or(text.trim());
-        let cleaned = cleaned.strip_suffix("*/").unwrap_or(cleaned);
+        if is_directive(text) {
+            return None;
+        }
 
-        for line in cleaned.lines() {
+        // Strip comment markers and check each line
+        for line in comment_body(text).lines() {
             let line = line.trim().strip_prefix('*').unwrap_or(line.trim()).trim();
             let lower = line.to_lowercase();
 
@@ -130,6 +144,9 @@ impl ReasoningArtifact {
                             "Remove this comment — it appears to be an AI chain-of-thought artifact."
                                 .to_string(),
                         ),
+                        fix: Some(Edit::delete_lines(source.as_bytes(), node.byte_range())),
+                        metrics: None,
+                        related: Vec::new(),
                     });
                 }
             }
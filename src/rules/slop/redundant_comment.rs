@@ -2,11 +2,12 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
-use crate::rules::Rule;
+use crate::parsers::{SemanticNode, is_doc_comment};
+use crate::rules::{Rule, RuleContext, RuleDocs, RuleOption, parse_options};
 use crate::tokens::{extract_code_tokens, extract_comment_tokens};
-use crate::types::{Finding, Severity};
+use crate::types::{Category, Edit, Finding, Severity};
+use serde::Deserialize;
 use std::collections::HashSet;
-use std::path::Path;
 
 const OVERLAP_THRESHOLD: f64 = 0.7;
 const MIN_COMMENT_WORDS: usize = 3;
@@ -15,11 +16,32 @@ const DIRECTIVE_PATTERNS: &[&str] = &[
     "todo", "fixme", "hack", "xxx", "note:", "bug",
     "eslint-disable", "eslint-enable", "@ts-ignore", "@ts-expect-error", "@ts-nocheck",
     "prettier-ignore", "istanbul ignore", "c8 ignore",
+    "noqa", "type: ignore", "pylint:", "pragma:", "fmt: off", "fmt: on",
+    "patina-ignore", "patina-disable", "patina-enable",
     "@param", "@returns", "@return", "@type", "@typedef", "@template",
     "@see", "@deprecated", "@example", "@throws",
 ];
 
-pub struct RedundantComment;
+pub struct RedundantComment {
+    threshold: f64,
+    min_comment_words: usize,
+}
+
+impl Default for RedundantComment {
+    fn default() -> Self {
+        Self {
+            threshold: OVERLAP_THRESHOLD,
+            min_comment_words: MIN_COMMENT_WORDS,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Options {
+    threshold: Option<f64>,
+    min_comment_words: Option<usize>,
+}
 
 impl Rule for RedundantComment {
     fn id(&self) -> &'static str {
@@ -34,40 +56,84 @@ impl Rule for RedundantComment {
     fn severity(&self) -> Severity {
         Severity::Warn
     }
+    fn category(&self) -> Category {
+        Category::Slop
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: "A comment that repeats the code it sits next to costs reading time without \
+                telling the reader anything new, and it silently goes stale when the code changes. \
+                Comments should carry what the code cannot: intent, constraints, and trade-offs.",
+            heuristic: "For each comment, the adjacent code is found (a statement on the same line, \
+                otherwise the next statement). The comment's words are stop-word filtered and \
+                stemmed; the code's identifiers are split on camelCase/snake_case and stemmed. If \
+                at least `threshold` (70%) of the comment's words appear in the code, the comment \
+                is flagged. Comments with fewer than `min_comment_words` words, JSDoc blocks, and \
+                directives (TODO, eslint-disable, patina-ignore, ...) are skipped.",
+            flagged_example: "// Set the user name\nuser.setUserName(name);",
+            not_flagged_example: "// Normalize Unicode before comparison to handle locale-specific equivalence\nuser.setName(normalizeName(name));",
+            options: &[
+                RuleOption {
+                    name: "threshold",
+                    default: "0.7",
+                    description: "Fraction of comment words that must appear in the code (0.0-1.0)",
+                },
+                RuleOption {
+                    name: "min_comment_words",
+                    default: "3",
+                    description: "Comments with fewer meaningful words are not analyzed",
+                },
+            ],
+            false_positives: &[
+                "Section labels above well-named calls, where the comment is used for visual grouping",
+                "Comments that add one crucial word (e.g. \"Set the user name lazily\") still reach the threshold",
+            ],
+        }
+    }
 
-    fn check(
-        &self,
-        source: &[u8],
-        tree: &tree_sitter::Tree,
-        file_path: &Path,
-    ) -> Vec<Finding> {
+    fn configure(&mut self, config: &toml::Value) -> Result<(), String> {
+        let options: Options = parse_options(config)?;
+        if let Some(threshold) = options.threshold {
+            if !(0.0..=1.0).contains(&threshold) {
+                return Err(format!("threshold must be between 0.0 and 1.0, got {threshold}"));
+            }
+            self.threshold = threshold;
+        }
+        if let Some(min_comment_words) = options.min_comment_words {
+            self.min_comment_words = min_comment_words;
+        }
+        Ok(())
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
         let mut findings = Vec::new();
-        let source_str = match std::str::from_utf8(source) {
+        let source_str = match std::str::from_utf8(ctx.source) {
             Ok(s) => s,
             Err(_) => {
-                eprintln!("Warning: {} is not valid UTF-8, skipping", file_path.display());
+                eprintln!("Warning: {} is not valid UTF-8, skipping", ctx.file_path.display());
                 return findings;
             }
         };
 
-        let mut cursor = tree.walk();
-        Self::walk_tree(&mut cursor, source_str, file_path, &mut findings);
+        let mut cursor = ctx.tree.walk();
+        self.walk_tree(ctx, &mut cursor, source_str, &mut findings);
         findings
     }
 }
 
 impl RedundantComment {
     fn walk_tree(
+        &self,
+        ctx: &RuleContext,
         cursor: &mut tree_sitter::TreeCursor,
         source: &str,
-        file_path: &Path,
         findings: &mut Vec<Finding>,
     ) {
         loop {
             let node = cursor.node();
 
-            if node.kind() == "comment"
-                && let Some(finding) = Self::check_comment(node, source, file_path) {
+            if ctx.is(node, SemanticNode::Comment)
+                && let Some(finding) = self.check_comment(ctx, node, source) {
                     findings.push(finding);
                 }
 
@@ -89,14 +155,15 @@ impl RedundantComment {
     }
 
     fn check_comment(
+        &self,
+        ctx: &RuleContext,
         node: tree_sitter::Node,
         source: &str,
-        file_path: &Path,
     ) -> Option<Finding> {
         let text = node.utf8_text(source.as_bytes()).ok()?;
 
-        // Skip JSDoc blocks
-        if text.starts_with("/**") {
+        // Skip JSDoc blocks and Rust doc comments
+        if is_doc_comment(text) {
             return None;
         }
 
@@ -111,15 +178,15 @@ impl RedundantComment {
 
         // Extract meaningful tokens from the comment
         let comment_tokens = extract_comment_tokens(text);
-        if comment_tokens.len() < MIN_COMMENT_WORDS {
+        if comment_tokens.len() < self.min_comment_words {
             return None;
         }
 
         // Find the adjacent code node
-        let code_node = Self::find_adjacent_code(node)?;
+        let code_node = Self::find_adjacent_code(ctx, node)?;
 
         // Collect identifiers from the code node
-        let identifiers = Self::collect_identifiers(code_node, source);
+        let identifiers = Self::collect_identifiers(ctx, code_node, source);
         if identifiers.is_empty() {
             return None;
         }
@@ -135,13 +202,13 @@ impl RedundantComment {
             .count();
         let overlap = matching as f64 / comment_tokens.len() as f64;
 
-        if overlap >= OVERLAP_THRESHOLD {
+        if overlap >= self.threshold {
             let start = node.start_position();
             Some(Finding {
                 rule_id: "",
                 message: "comment restates the adjacent code".to_string(),
                 severity: Severity::Warn,
-                file: file_path.to_path_buf(),
+                file: ctx.file_path.to_path_buf(),
                 line: start.row + 1,
                 column: start.column + 1,
                 span: node.byte_range(),
@@ -149,18 +216,24 @@ impl RedundantComment {
                     "Remove this comment — it restates the code without adding context."
                         .to_string(),
                 ),
+                fix: Some(Edit::delete_lines(source.as_bytes(), node.byte_range())),
+                metrics: None,
+                related: Vec::new(),
             })
         } else {
             None
         }
     }
 
-    fn find_adjacent_code(comment_node: tree_sitter::Node) -> Option<tree_sitter::Node> {
+    fn find_adjacent_code<'t>(
+        ctx: &RuleContext,
+        comment_node: tree_sitter::Node<'t>,
+    ) -> Option<tree_sitter::Node<'t>> {
         // Check previous sibling first for inline/trailing comments
         // (comment on the same line as code it annotates)
         let mut sibling = comment_node.prev_named_sibling();
         while let Some(s) = sibling {
-            if s.kind() != "comment" {
+            if !ctx.is(s, SemanticNode::Comment) {
                 if s.end_position().row == comment_node.start_position().row {
                     return Some(s);
                 }
@@ -172,7 +245,7 @@ impl RedundantComment {
         // Otherwise, try next named sibling (comment above code)
         let mut sibling = comment_node.next_named_sibling();
         while let Some(s) = sibling {
-            if s.kind() != "comment" {
+            if !ctx.is(s, SemanticNode::Comment) {
                 return Some(s);
             }
             sibling = s.next_named_sibling();
@@ -181,12 +254,15 @@ impl RedundantComment {
         None
     }
 
-    fn collect_identifiers(node: tree_sitter::Node, source: &str) -> Vec<String> {
+    fn collect_identifiers(
+        ctx: &RuleContext,
+        node: tree_sitter::Node,
+        source: &str,
+    ) -> Vec<String> {
         let mut identifiers = Vec::new();
         let mut cursor = node.walk();
         loop {
-            let kind = cursor.node().kind();
-            if (kind == "identifier" || kind == "property_identifier" || kind == "shorthand_property_identifier" || kind == "shorthand_property_identifier_pattern")
+            if ctx.is(cursor.node(), SemanticNode::Identifier)
                 && let Ok(text) = cursor.node().utf8_text(source.as_bytes()) {
                     identifiers.push(text.to_string());
                 }
@@ -0,0 +1,285 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::parsers::{SemanticNode, is_doc_comment};
+use crate::rules::{Rule, RuleContext, RuleDocs, RuleOption, parse_options};
+use crate::suppression::is_directive;
+use crate::types::{Category, Edit, Finding, Severity};
+use serde::Deserialize;
+
+/// Characters that rule lines and banner decorations are drawn with.
+const RULE_CHARS: &[char] = &['=', '-', '*', '#', '~', '_', '+', '/', '\\', '|', '<', '>'];
+
+/// Shortest run of rule characters that counts as a rule line.
+const MIN_RULE_LENGTH: usize = 4;
+
+/// Shortest run of rule characters that decorates a heading: `--- Helpers ---`.
+const MIN_DECORATION_LENGTH: usize = 3;
+
+/// Longest line, in words, that still reads as a section heading.
+const MAX_HEADING_WORDS: usize = 6;
+
+/// Editor folding markers (VS Code, JetBrains, Vim, Xcode).
+const FOLD_MARKERS: &[&str] = &[
+    "#region",
+    "#endregion",
+    "region ",
+    "endregion",
+    "<editor-fold",
+    "</editor-fold>",
+    "{{{",
+    "}}}",
+    "MARK:",
+];
+
+/// Words that mark a comment as a license or copyright header.
+const LICENSE_MARKERS: &[&str] = &["license", "copyright", "spdx-"];
+
+pub struct SectionBanner {
+    allow_fold_markers: bool,
+}
+
+impl Default for SectionBanner {
+    fn default() -> Self {
+        Self {
+            allow_fold_markers: true,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Options {
+    allow_fold_markers: Option<bool>,
+}
+
+/// How one line of a comment reads.
+#[derive(PartialEq)]
+enum BannerLine {
+    /// Nothing but rule characters: `==========`.
+    Rule,
+    /// A heading drawn with rule characters: `===== HELPERS =====`.
+    Decorated,
+    /// An editor fold marker such as `#region`.
+    FoldMarker,
+    /// A short line that could be the heading inside a box.
+    Heading,
+    Prose,
+}
+
+impl Rule for SectionBanner {
+    fn id(&self) -> &'static str {
+        "slop-006"
+    }
+    fn name(&self) -> &'static str {
+        "Section Banner"
+    }
+    fn description(&self) -> &'static str {
+        "Detects decorative divider and banner comments that segment code"
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warn
+    }
+    fn category(&self) -> Category {
+        Category::Slop
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: "Banners such as `// ========== HELPERS ==========` add visual noise \
+                without information. A file that needs signposts is usually a file that should be \
+                split into modules, and the functions' names already say what each part does.",
+            heuristic: "Consecutive `//` or `#` lines on their own lines are grouped, and each \
+                `/* */` block is one group. A group is flagged when every line is a rule line (4 \
+                or more of `=-*#~_+/\\|<>` and nothing else), a heading decorated with runs of \
+                those characters (`--- Helpers ---`, `Helpers -----`), or a short heading (up to \
+                6 words) boxed between such lines, and at least one line is decoration. License \
+                and copyright headers, JSDoc, doc comments and patina directives are exempt. \
+                Editor fold markers (`#region`, `{{{`, `MARK:`) exempt their comment unless \
+                `allow_fold_markers` is false, in which case they count as decoration.",
+            flagged_example: "// ========== HELPERS ==========\nfunction add(a, b) {\n  return a + b;\n}",
+            not_flagged_example: "// Arithmetic helpers shared by the invoice and quote views\nfunction add(a, b) {\n  return a + b;\n}",
+            options: &[RuleOption {
+                name: "allow_fold_markers",
+                default: "true",
+                description: "Leave comments containing editor fold markers such as `#region` alone",
+            }],
+            false_positives: &[
+                "ASCII tables or diagrams made only of rule characters",
+                "Banners required by a project's style guide",
+            ],
+        }
+    }
+
+    fn configure(&mut self, config: &toml::Value) -> Result<(), String> {
+        let options: Options = parse_options(config)?;
+        if let Some(allow_fold_markers) = options.allow_fold_markers {
+            self.allow_fold_markers = allow_fold_markers;
+        }
+        Ok(())
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut comments = Vec::new();
+        let mut cursor = ctx.tree.walk();
+        Self::collect_comments(ctx, &mut cursor, &mut comments);
+
+        let mut findings = Vec::new();
+        let mut i = 0;
+        while i < comments.len() {
+            // Block comments stand alone; line comments group with the lines below
+            let mut end = i + 1;
+            if Self::is_line_comment(ctx, comments[i]) {
+                while end < comments.len()
+                    && Self::is_line_comment(ctx, comments[end])
+                    && comments[end].start_position().row == comments[end - 1].start_position().row + 1
+                {
+                    end += 1;
+                }
+            }
+            if let Some(finding) = self.check_group(ctx, &comments[i..end]) {
+                findings.push(finding);
+            }
+            i = end;
+        }
+        findings
+    }
+}
+
+impl SectionBanner {
+    /// Collects comments that sit on their own line and are not exempt.
+    fn collect_comments<'a>(
+        ctx: &RuleContext,
+        cursor: &mut tree_sitter::TreeCursor<'a>,
+        comments: &mut Vec<tree_sitter::Node<'a>>,
+    ) {
+        loop {
+            let node = cursor.node();
+            if ctx.is(node, SemanticNode::Comment)
+                && Self::is_own_line(ctx.source, node)
+                && node.utf8_text(ctx.source).is_ok_and(|text| !Self::is_exempt(text))
+            {
+                comments.push(node);
+            }
+
+            if cursor.goto_first_child() {
+                continue;
+            }
+            if cursor.goto_next_sibling() {
+                continue;
+            }
+            loop {
+                if !cursor.goto_parent() {
+                    return;
+                }
+                if cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn is_own_line(source: &[u8], node: tree_sitter::Node) -> bool {
+        let line_start = source[..node.start_byte()]
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |i| i + 1);
+        source[line_start..node.start_byte()]
+            .iter()
+            .all(|b| b.is_ascii_whitespace())
+    }
+
+    /// JSDoc and doc comments, directives and test annotations. A `/***` run
+    /// opens a box, not a JSDoc block.
+    fn is_exempt(text: &str) -> bool {
+        let body = text.trim_start_matches(['/', '#']).trim();
+        (is_doc_comment(text) && !text.starts_with("/***"))
+            || is_directive(text)
+            || body.starts_with("expect:")
+    }
+
+    fn is_line_comment(ctx: &RuleContext, node: tree_sitter::Node) -> bool {
+        node.utf8_text(ctx.source)
+            .is_ok_and(|text| text.starts_with("//") || text.starts_with('#'))
+    }
+
+    fn check_group(&self, ctx: &RuleContext, group: &[tree_sitter::Node]) -> Option<Finding> {
+        let mut lines = Vec::new();
+        for node in group {
+            let text = node.utf8_text(ctx.source).ok()?;
+            lines.extend(text.lines().map(Self::strip_markers).filter(|line| !line.is_empty()));
+        }
+
+        let lower = lines.join("\n").to_lowercase();
+        if LICENSE_MARKERS.iter().any(|marker| lower.contains(marker)) {
+            return None;
+        }
+
+        let kinds: Vec<BannerLine> = lines.iter().map(|line| Self::classify(line)).collect();
+        if self.allow_fold_markers && kinds.contains(&BannerLine::FoldMarker) {
+            return None;
+        }
+        let decorated = kinds
+            .iter()
+            .any(|kind| matches!(kind, BannerLine::Rule | BannerLine::Decorated | BannerLine::FoldMarker));
+        if !decorated || kinds.contains(&BannerLine::Prose) {
+            return None;
+        }
+
+        let (first, last) = (group.first()?, group.last()?);
+        let span = first.start_byte()..last.end_byte();
+        let start = first.start_position();
+        Some(Finding {
+            rule_id: "",
+            message: "decorative section banner".to_string(),
+            severity: Severity::Warn,
+            file: ctx.file_path.to_path_buf(),
+            line: start.row + 1,
+            column: start.column + 1,
+            span: span.clone(),
+            suggestion: Some(
+                "Remove the banner; if the file needs sections, split it into modules.".to_string(),
+            ),
+            fix: Some(Edit::delete_lines(ctx.source, span)),
+            metrics: None,
+            related: Vec::new(),
+        })
+    }
+
+    /// Removes one comment marker from each end of a raw comment line:
+    /// `//`, `#`, `/*`, `*/`.
+    fn strip_markers(line: &str) -> &str {
+        let line = line.trim();
+        let line = ["//", "/*", "#"]
+            .iter()
+            .find_map(|marker| line.strip_prefix(marker))
+            .unwrap_or(line);
+        line.strip_suffix("*/").unwrap_or(line).trim()
+    }
+
+    fn classify(line: &str) -> BannerLine {
+        if FOLD_MARKERS.iter().any(|marker| line.starts_with(marker)) {
+            return BannerLine::FoldMarker;
+        }
+        if line.chars().all(|c| RULE_CHARS.contains(&c) || c.is_whitespace()) {
+            return if line.chars().filter(|c| !c.is_whitespace()).count() >= MIN_RULE_LENGTH {
+                BannerLine::Rule
+            } else {
+                BannerLine::Prose
+            };
+        }
+
+        let trailing = line.chars().rev().take_while(|c| RULE_CHARS.contains(c)).count();
+        let heading = line.trim_matches(|c: char| RULE_CHARS.contains(&c) || c.is_whitespace());
+        let is_heading = heading.split_whitespace().count() <= MAX_HEADING_WORDS
+            && !heading.ends_with(['.', ':', ';', ',', '?', '!']);
+        if !is_heading {
+            BannerLine::Prose
+        } else if trailing >= MIN_DECORATION_LENGTH {
+            BannerLine::Decorated
+        } else {
+            // A leading run alone (`### Usage`, `--- note`) is not decoration
+            BannerLine::Heading
+        }
+    }
+}
@@ -2,8 +2,11 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
-use crate::rules::Rule;
-use crate::types::{Finding, Severity};
+use crate::parsers::{SemanticNode, is_doc_comment};
+use crate::rules::{Rule, RuleContext, RuleDocs};
+use crate::suppression::is_directive;
+use crate::tokens::comment_body;
+use crate::types::{Category, Finding, Severity};
 use std::path::Path;
 
 /// Phrase-start patterns that indicate self-narrating/explanatory comments.
@@ -40,37 +43,52 @@ impl Rule for SelfNarrating {
     fn severity(&self) -> Severity {
         Severity::Warn
     }
+    fn category(&self) -> Category {
+        Category::Slop
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: "Narration such as \"Here we loop over the users\" walks the reader through \
+                what the code visibly does. It adds length, not understanding; a useful comment \
+                explains why the code is the way it is.",
+            heuristic: "Each comment line is checked for first-person or narrating openers (\"here \
+                we\", \"we need to\", \"let's make sure\", \"this function\", \"the following \
+                code\", ...) at the start of the line, case-insensitively. JSDoc blocks are \
+                skipped.",
+            flagged_example: "// Here we loop over the users and send each an email\nfor (const user of users) sendEmail(user);",
+            not_flagged_example: "// Emails go out one at a time to stay under the provider's rate limit\nfor (const user of users) await sendEmail(user);",
+            options: &[],
+            false_positives: &[
+                "\"This function\" in a plain `//` comment that documents an API contract",
+            ],
+        }
+    }
 
-    fn check(
-        &self,
-        source: &[u8],
-        tree: &tree_sitter::Tree,
-        file_path: &Path,
-    ) -> Vec<Finding> {
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
         let mut findings = Vec::new();
-        let source_str = match std::str::from_utf8(source) {
+        let source_str = match std::str::from_utf8(ctx.source) {
             Ok(s) => s,
             Err(_) => return findings,
         };
 
-        let mut cursor = tree.walk();
-        Self::walk_tree(&mut cursor, source_str, file_path, &mut findings);
+        let mut cursor = ctx.tree.walk();
+        Self::walk_tree(ctx, &mut cursor, source_str, &mut findings);
         findings
     }
 }
 
 impl SelfNarrating {
     fn walk_tree(
+        ctx: &RuleContext,
         cursor: &mut tree_sitter::TreeCursor,
         source: &str,
-        file_path: &Path,
         findings: &mut Vec<Finding>,
     ) {
         loop {
             let node = cursor.node();
 
-            if node.kind() == "comment"
-                && let Some(finding) = Self::check_comment(node, source, file_path)
+            if ctx.is(node, SemanticNode::Comment)
+                && let Some(finding) = Self::check_comment(node, source, ctx.file_path)
             {
                 findings.push(finding);
             }
@@ -99,20 +117,13 @@ impl SelfNarrating {
     ) -> Option<Finding> {
         let text = node.utf8_text(source.as_bytes()).ok()?;
 
-        // Skip JSDoc blocks
-        if text.starts_with("/**") {
+        // Skip JSDoc blocks, Rust doc comments and suppression directives
+        if is_doc_comment(text) || is_directive(text) {
             return None;
         }
 
         // Strip comment markers and check each line
-        let cleaned = text
-            .trim()
-            .strip_prefix("//")
-            .or_else(|| text.trim().strip_prefix("/*"))
-            .unwrap_or(text.trim());
-        let cleaned = cleaned.strip_suffix("*/").unwrap_or(cleaned);
-
-        for line in cleaned.lines() {
+        for line in comment_body(text).lines() {
             let line = line.trim().strip_prefix('*').unwrap_or(line.trim()).trim();
             let lower = line.to_lowercase();
 
@@ -131,6 +142,9 @@ impl SelfNarrating {
                             "Rewrite to explain *why*, not narrate *what* — or remove if the code is self-explanatory."
                                 .to_string(),
                         ),
+                        fix: None,
+                        metrics: None,
+                        related: Vec::new(),
                     });
                 }
             }
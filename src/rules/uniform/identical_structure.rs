@@ -0,0 +1,313 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::parsers::SemanticNode;
+use crate::rules::{Rule, RuleContext, RuleDocs, RuleOption, parse_options};
+use crate::types::{Category, Finding, RelatedLocation, Severity};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+const THRESHOLD: f64 = 0.9;
+const MIN_NODES: usize = 30;
+
+/// Length of the runs of node kinds compared between fingerprints.
+const SHINGLE_LENGTH: usize = 5;
+
+/// Fingerprint tokens for names, literals and the end of a node's children,
+/// outside the range of tree-sitter kind IDs.
+const IDENTIFIER: u16 = u16::MAX;
+const LITERAL: u16 = u16::MAX - 1;
+const CLOSE: u16 = u16::MAX - 2;
+
+pub struct IdenticalStructure {
+    threshold: f64,
+    min_nodes: usize,
+}
+
+impl Default for IdenticalStructure {
+    fn default() -> Self {
+        Self {
+            threshold: THRESHOLD,
+            min_nodes: MIN_NODES,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Options {
+    threshold: Option<f64>,
+    min_nodes: Option<usize>,
+}
+
+/// The shape of one function with names and literal values removed.
+struct Fingerprint<'t> {
+    function: tree_sitter::Node<'t>,
+    nodes: usize,
+    shingles: HashSet<u64>,
+}
+
+impl Rule for IdenticalStructure {
+    fn id(&self) -> &'static str {
+        "uniform-001"
+    }
+    fn name(&self) -> &'static str {
+        "Identical Structure"
+    }
+    fn description(&self) -> &'static str {
+        "Detects groups of functions in a file that share the same structure"
+    }
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+    fn category(&self) -> Category {
+        Category::Uniformity
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: "Functions that differ only in names and literals are usually one function \
+                pasted several times, a sign of generated code. Every copy has to be changed when \
+                the logic changes, and the copies drift apart when one is missed.",
+            heuristic: "Each function, method, arrow function and closure is reduced to the \
+                sequence of its syntax node kinds, with every name and literal replaced by a \
+                placeholder and comments dropped. Functions with fewer than `min_nodes` nodes are \
+                skipped. Two functions are similar when the Jaccard similarity of their runs of 5 \
+                consecutive node kinds reaches `threshold`; similar functions are grouped \
+                transitively and each group is reported once, on its first member, with a related \
+                location for every member and the similarity of its least alike pair.",
+            flagged_example: "function loadUser(id) {\n  const response = fetch(`/users/${id}`);\n  if (!response.ok) {\n    throw new Error(\"user not found\");\n  }\n  return response.json();\n}\n\nfunction loadOrder(id) {\n  const response = fetch(`/orders/${id}`);\n  if (!response.ok) {\n    throw new Error(\"order not found\");\n  }\n  return response.json();\n}",
+            not_flagged_example: "function load(kind, id) {\n  const response = fetch(`/${kind}/${id}`);\n  if (!response.ok) {\n    throw new Error(`${kind} not found`);\n  }\n  return response.json();\n}",
+            options: &[
+                RuleOption {
+                    name: "threshold",
+                    default: "0.9",
+                    description: "Similarity from 0.0 to 1.0 at which two functions are grouped",
+                },
+                RuleOption {
+                    name: "min_nodes",
+                    default: "30",
+                    description: "Smallest function, in syntax nodes, that is compared",
+                },
+            ],
+            false_positives: &[
+                "Small adapters that must exist separately, such as one handler per event type",
+                "Test cases that follow the same arrange-act-assert shape",
+            ],
+        }
+    }
+
+    fn configure(&mut self, config: &toml::Value) -> Result<(), String> {
+        let options: Options = parse_options(config)?;
+        if let Some(threshold) = options.threshold {
+            if !(threshold > 0.0 && threshold <= 1.0) {
+                return Err(format!("threshold must be above 0.0 and at most 1.0, got {threshold}"));
+            }
+            self.threshold = threshold;
+        }
+        if let Some(min_nodes) = options.min_nodes {
+            self.min_nodes = min_nodes;
+        }
+        Ok(())
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut functions = Vec::new();
+        let mut cursor = ctx.tree.walk();
+        Self::collect_functions(ctx, &mut cursor, &mut functions);
+
+        let fingerprints: Vec<Fingerprint> = functions
+            .into_iter()
+            .map(|function| fingerprint(ctx, function))
+            .filter(|fingerprint| fingerprint.nodes >= self.min_nodes)
+            .collect();
+
+        // Union-find over every pair above the threshold
+        let count = fingerprints.len();
+        let mut parent: Vec<usize> = (0..count).collect();
+        let mut similarities = vec![1.0_f64; count * count];
+        for i in 0..count {
+            for j in i + 1..count {
+                let similarity = jaccard(&fingerprints[i].shingles, &fingerprints[j].shingles);
+                similarities[i * count + j] = similarity;
+                if similarity >= self.threshold {
+                    let (a, b) = (find(&mut parent, i), find(&mut parent, j));
+                    parent[b] = a;
+                }
+            }
+        }
+
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        let mut group_of_root: Vec<Option<usize>> = vec![None; fingerprints.len()];
+        for i in 0..fingerprints.len() {
+            let root = find(&mut parent, i);
+            match group_of_root[root] {
+                Some(group) => groups[group].push(i),
+                None => {
+                    group_of_root[root] = Some(groups.len());
+                    groups.push(vec![i]);
+                }
+            }
+        }
+
+        groups
+            .into_iter()
+            .filter(|group| group.len() >= 2)
+            .map(|group| {
+                // Members grouped transitively can be less alike than any
+                // merging pair, so the figure covers every pair in the group
+                let similarity = group
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(k, &i)| group[k + 1..].iter().map(move |&j| (i, j)))
+                    .map(|(i, j)| similarities[i * count + j])
+                    .fold(1.0_f64, f64::min);
+                Self::finding(ctx, &fingerprints, &group, similarity)
+            })
+            .collect()
+    }
+}
+
+impl IdenticalStructure {
+    fn collect_functions<'t>(
+        ctx: &RuleContext,
+        cursor: &mut tree_sitter::TreeCursor<'t>,
+        functions: &mut Vec<tree_sitter::Node<'t>>,
+    ) {
+        loop {
+            let node = cursor.node();
+            if ctx.is(node, SemanticNode::Function) || ctx.is(node, SemanticNode::Closure) {
+                functions.push(node);
+            }
+
+            if cursor.goto_first_child() {
+                continue;
+            }
+            if cursor.goto_next_sibling() {
+                continue;
+            }
+            loop {
+                if !cursor.goto_parent() {
+                    return;
+                }
+                if cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn finding(ctx: &RuleContext, fingerprints: &[Fingerprint], group: &[usize], similarity: f64) -> Finding {
+        let describe = |function: tree_sitter::Node| match ctx.function_name(function) {
+            Some(name) => format!("`{name}`"),
+            None => format!("anonymous function at line {}", function.start_position().row + 1),
+        };
+        let names: Vec<String> = group.iter().map(|&i| describe(fingerprints[i].function)).collect();
+        let related = group
+            .iter()
+            .map(|&i| {
+                let function = fingerprints[i].function;
+                let start = function.start_position();
+                RelatedLocation {
+                    file: ctx.file_path.to_path_buf(),
+                    line: start.row + 1,
+                    column: start.column + 1,
+                    span: signature_span(ctx, function),
+                    message: format!("{} has the same structure", describe(function)),
+                }
+            })
+            .collect();
+
+        let degree = if similarity < 1.0 {
+            format!(" (at least {:.0}% similar)", (similarity * 100.0).floor())
+        } else {
+            String::new()
+        };
+
+        let first = fingerprints[group[0]].function;
+        let start = first.start_position();
+        Finding {
+            rule_id: "",
+            message: format!(
+                "{} functions share the same structure{degree}: {}",
+                group.len(),
+                names.join(", ")
+            ),
+            severity: Severity::Info,
+            file: ctx.file_path.to_path_buf(),
+            line: start.row + 1,
+            column: start.column + 1,
+            span: signature_span(ctx, first),
+            suggestion: Some(
+                "Extract the shared logic into one function and pass in what differs.".to_string(),
+            ),
+            fix: None,
+            metrics: None,
+            related,
+        }
+    }
+}
+
+/// The function's first line.
+fn signature_span(ctx: &RuleContext, function: tree_sitter::Node) -> std::ops::Range<usize> {
+    let end = ctx.source[function.start_byte()..function.end_byte()]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(function.end_byte(), |i| function.start_byte() + i);
+    function.start_byte()..end
+}
+
+fn fingerprint<'t>(ctx: &RuleContext, function: tree_sitter::Node<'t>) -> Fingerprint<'t> {
+    let mut tokens = Vec::new();
+    push_tokens(ctx, function, &mut tokens);
+    let shingles = tokens
+        .windows(SHINGLE_LENGTH.min(tokens.len()).max(1))
+        .map(|window| {
+            let mut hasher = DefaultHasher::new();
+            window.hash(&mut hasher);
+            hasher.finish()
+        })
+        .collect();
+    Fingerprint {
+        function,
+        nodes: tokens.iter().filter(|&&token| token != CLOSE).count(),
+        shingles,
+    }
+}
+
+/// Appends the named nodes under `node` in pre-order, closing each node that
+/// has children so nesting is part of the sequence.
+fn push_tokens(ctx: &RuleContext, node: tree_sitter::Node, tokens: &mut Vec<u16>) {
+    if ctx.is(node, SemanticNode::Comment) {
+        return;
+    }
+    if ctx.is(node, SemanticNode::Identifier) {
+        tokens.push(IDENTIFIER);
+        return;
+    }
+    if node.named_child_count() == 0 {
+        tokens.push(LITERAL);
+        return;
+    }
+    tokens.push(node.kind_id());
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        push_tokens(ctx, child, tokens);
+    }
+    tokens.push(CLOSE);
+}
+
+fn jaccard(a: &HashSet<u64>, b: &HashSet<u64>) -> f64 {
+    let shared = a.intersection(b).count();
+    let total = a.len() + b.len() - shared;
+    if total == 0 { 1.0 } else { shared as f64 / total as f64 }
+}
+
+fn find(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
@@ -0,0 +1,507 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::parsers::{Language, SemanticNode};
+use crate::rules::{DynProjectRule, ProjectFile, ProjectRule, Rule, RuleContext, RuleDocs, RuleOption, parse_options};
+use crate::tokens::split_identifier;
+use crate::types::{Category, Finding, RelatedLocation, Severity};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::ops::Range;
+
+const MIN_NAMES: usize = 5;
+const MIN_DEVIATIONS: usize = 2;
+
+/// A casing, prefix or suffix sets a file's template when at least this share
+/// of its names use it, and at least `MIN_TEMPLATE_NAMES` of them.
+const DOMINANT_SHARE: f64 = 0.5;
+const MIN_TEMPLATE_NAMES: usize = 3;
+
+/// ...and deviates when the rest of the project uses it for fewer than this
+/// share of its names.
+const RARE_SHARE: f64 = 0.1;
+
+/// Words per name deviate when the file's mean is this far from the project's,
+/// and at least two standard deviations away.
+const MIN_WORDS_DIFFERENCE: f64 = 1.5;
+
+pub struct TemplateNaming {
+    min_names: usize,
+    min_deviations: usize,
+}
+
+impl Default for TemplateNaming {
+    fn default() -> Self {
+        Self {
+            min_names: MIN_NAMES,
+            min_deviations: MIN_DEVIATIONS,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Options {
+    min_names: Option<usize>,
+    min_deviations: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Casing {
+    Camel,
+    Pascal,
+    Snake,
+    Screaming,
+}
+
+impl Casing {
+    /// Single lowercase words fit both camelCase and snake_case, and names
+    /// mixing underscores with capitals fit none, so neither has a casing.
+    fn of(name: &str) -> Option<Self> {
+        let name = name.trim_matches(|c| c == '_' || c == '$');
+        let has_upper = name.chars().any(char::is_uppercase);
+        let has_lower = name.chars().any(char::is_lowercase);
+        if name.contains('_') {
+            return match (has_upper, has_lower) {
+                (false, _) => Some(Self::Snake),
+                (true, false) => Some(Self::Screaming),
+                (true, true) => None,
+            };
+        }
+        match (has_upper, has_lower) {
+            (false, _) => None,
+            (true, false) => Some(Self::Screaming),
+            _ if name.starts_with(char::is_uppercase) => Some(Self::Pascal),
+            _ => Some(Self::Camel),
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Camel => "camelCase",
+            Self::Pascal => "PascalCase",
+            Self::Snake => "snake_case",
+            Self::Screaming => "SCREAMING_CASE",
+        }
+    }
+}
+
+/// One function name and its shape.
+struct Name {
+    text: String,
+    casing: Option<Casing>,
+    words: Vec<String>,
+    line: usize,
+    column: usize,
+    span: Range<usize>,
+}
+
+/// The function names of one file, kept until every file has been seen.
+pub struct FileNames {
+    language: Language,
+    names: Vec<Name>,
+}
+
+/// Counts of name shapes over a file or a set of files.
+#[derive(Default, Clone)]
+struct Profile {
+    names: usize,
+    /// Names with a casing; the denominator for casing shares.
+    cased: usize,
+    casings: HashMap<Casing, usize>,
+    /// Names of two or more words; the denominator for prefix and suffix shares.
+    compound: usize,
+    prefixes: HashMap<String, usize>,
+    suffixes: HashMap<String, usize>,
+    words: usize,
+    words_squared: usize,
+}
+
+impl Profile {
+    fn of(names: &[Name]) -> Self {
+        let mut profile = Self::default();
+        for name in names {
+            profile.names += 1;
+            if let Some(casing) = name.casing {
+                profile.cased += 1;
+                *profile.casings.entry(casing).or_default() += 1;
+            }
+            if let [first, .., last] = name.words.as_slice() {
+                profile.compound += 1;
+                *profile.prefixes.entry(first.clone()).or_default() += 1;
+                *profile.suffixes.entry(last.clone()).or_default() += 1;
+            }
+            profile.words += name.words.len();
+            profile.words_squared += name.words.len() * name.words.len();
+        }
+        profile
+    }
+
+    fn add(&mut self, other: &Self) {
+        self.combine(other, |total, count| total + count);
+    }
+
+    fn remove(&mut self, other: &Self) {
+        self.combine(other, |total, count| total - count);
+    }
+
+    fn combine(&mut self, other: &Self, op: impl Fn(usize, usize) -> usize) {
+        self.names = op(self.names, other.names);
+        self.cased = op(self.cased, other.cased);
+        self.compound = op(self.compound, other.compound);
+        self.words = op(self.words, other.words);
+        self.words_squared = op(self.words_squared, other.words_squared);
+        for (casing, &count) in &other.casings {
+            let total = self.casings.entry(*casing).or_default();
+            *total = op(*total, count);
+        }
+        for (mine, theirs) in [(&mut self.prefixes, &other.prefixes), (&mut self.suffixes, &other.suffixes)] {
+            for (word, &count) in theirs {
+                let total = mine.entry(word.clone()).or_default();
+                *total = op(*total, count);
+            }
+        }
+    }
+
+    fn casing_share(&self, casing: Casing) -> f64 {
+        share(self.casings.get(&casing).copied().unwrap_or(0), self.cased)
+    }
+
+    fn prefix_share(&self, word: &str) -> f64 {
+        share(self.prefixes.get(word).copied().unwrap_or(0), self.compound)
+    }
+
+    fn suffix_share(&self, word: &str) -> f64 {
+        share(self.suffixes.get(word).copied().unwrap_or(0), self.compound)
+    }
+
+    fn mean_words(&self) -> f64 {
+        share(self.words, self.names)
+    }
+
+    fn words_deviation(&self) -> f64 {
+        let mean = self.mean_words();
+        (share(self.words_squared, self.names) - mean * mean).max(0.0).sqrt()
+    }
+
+    /// The most used casing, ties going to the first in declaration order.
+    fn top_casing(&self) -> Option<(Casing, usize)> {
+        let mut casings: Vec<(Casing, usize)> = self.casings.iter().map(|(&c, &n)| (c, n)).collect();
+        casings.sort_by_key(|&(casing, count)| (std::cmp::Reverse(count), casing));
+        casings.into_iter().next().filter(|&(_, count)| count > 0)
+    }
+}
+
+/// The most used word, ties going to the alphabetically first.
+fn top_word(words: &HashMap<String, usize>) -> Option<(&str, usize)> {
+    words
+        .iter()
+        .filter(|&(_, &count)| count > 0)
+        .min_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)))
+        .map(|(word, &count)| (word.as_str(), count))
+}
+
+fn share(count: usize, total: usize) -> f64 {
+    if total == 0 { 0.0 } else { count as f64 / total as f64 }
+}
+
+fn percent(share: f64) -> String {
+    format!("{:.0}%", share * 100.0)
+}
+
+/// How one file's names depart from the rest of the project.
+#[derive(Default)]
+struct Deviations {
+    casing: Option<Casing>,
+    prefix: Option<String>,
+    suffix: Option<String>,
+    /// The file's mean words per name, when it is out of line.
+    words: Option<f64>,
+}
+
+impl Deviations {
+    fn count(&self) -> usize {
+        [self.casing.is_some(), self.prefix.is_some(), self.suffix.is_some(), self.words.is_some()]
+            .iter()
+            .filter(|&&deviates| deviates)
+            .count()
+    }
+
+    /// True if `name` is one of those that make the file deviate.
+    fn matches(&self, name: &Name, baseline_words: f64) -> bool {
+        let first = name.words.first().filter(|_| name.words.len() >= 2);
+        let last = name.words.last().filter(|_| name.words.len() >= 2);
+        self.casing.is_some_and(|casing| name.casing == Some(casing))
+            || self.prefix.as_ref().is_some_and(|prefix| first == Some(prefix))
+            || self.suffix.as_ref().is_some_and(|suffix| last == Some(suffix))
+            || self.words.is_some_and(|mean| {
+                (name.words.len() as f64 - baseline_words) * (mean - baseline_words).signum() >= 1.0
+            })
+    }
+}
+
+impl Rule for TemplateNaming {
+    fn id(&self) -> &'static str {
+        "uniform-002"
+    }
+    fn name(&self) -> &'static str {
+        "Template Naming"
+    }
+    fn description(&self) -> &'static str {
+        "Detects files whose function names follow a template unlike the rest of the project"
+    }
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+    fn category(&self) -> Category {
+        Category::Uniformity
+    }
+    fn docs(&self) -> RuleDocs {
+        RuleDocs {
+            rationale: "Generated modules tend to name everything from one rigid template, such as \
+                `handleXxxClick` or `xxxHelper`, whatever the rest of the codebase does. A file \
+                whose names share a shape nothing else in the project uses was likely pasted in \
+                rather than written to fit.",
+            heuristic: "Function names, including methods and functions bound to variables, are \
+                split into words and profiled by casing, first word, last word and word count. \
+                Variables, constants, parameters, classes and properties are not profiled: their \
+                names follow conventions of their own, such as SCREAMING_CASE constants and \
+                single-word loop variables, that would blur the function-name template. \
+                Each file with at least `min_names` names is compared with the rest of the \
+                project in the same language. A dimension deviates when a casing, prefix or \
+                suffix is used by at least half of the file's names (and at least 3) but by under \
+                10% elsewhere, or when the mean word count is at least 1.5 words and two standard \
+                deviations from the project's. Files with at least `min_deviations` deviating \
+                dimensions are reported with both profiles, and with a related location for each \
+                name that follows the template.",
+            flagged_example: "// file: cart.js\nfunction addItem(cart, item) { cart.push(item); }\nfunction removeItem(cart, item) { cart.delete(item); }\nfunction totalPrice(cart) { return cart.sum(); }\nfunction applyDiscount(cart, code) { cart.apply(code); }\nfunction formatPrice(price) { return price.toFixed(2); }\n// file: user.js\nfunction loadUser(id) { return fetch(id); }\nfunction saveUser(user) { return store(user); }\nfunction validateEmail(email) { return email.includes(\"@\"); }\nfunction formatName(user) { return user.name; }\nfunction parseDate(text) { return new Date(text); }\n// file: checkout.js\nfunction handleCartSubmitClick(event) { submit(event); }\nfunction handleCouponApplyClick(event) { apply(event); }\nfunction handlePaymentSelectClick(event) { select(event); }\nfunction handleAddressChangeClick(event) { change(event); }\nfunction handleOrderConfirmClick(event) { confirm(event); }",
+            not_flagged_example: "// file: cart.js\nfunction addItem(cart, item) { cart.push(item); }\nfunction removeItem(cart, item) { cart.delete(item); }\nfunction totalPrice(cart) { return cart.sum(); }\nfunction applyDiscount(cart, code) { cart.apply(code); }\nfunction formatPrice(price) { return price.toFixed(2); }\n// file: user.js\nfunction loadUser(id) { return fetch(id); }\nfunction saveUser(user) { return store(user); }\nfunction validateEmail(email) { return email.includes(\"@\"); }\nfunction formatName(user) { return user.name; }\nfunction parseDate(text) { return new Date(text); }\n// file: checkout.js\nfunction submitCart(event) { submit(event); }\nfunction applyCoupon(event) { apply(event); }\nfunction selectPayment(event) { select(event); }\nfunction changeAddress(event) { change(event); }\nfunction confirmOrder(event) { confirm(event); }",
+            options: &[
+                RuleOption {
+                    name: "min_names",
+                    default: "5",
+                    description: "Fewest function names a file, and the rest of the project, need to be compared",
+                },
+                RuleOption {
+                    name: "min_deviations",
+                    default: "2",
+                    description: "Deviating dimensions (casing, prefix, suffix, word count) needed to report a file",
+                },
+            ],
+            false_positives: &[
+                "Event handler or route modules where a `handle`/`on` prefix is the convention",
+                "Bindings to a foreign API that keep its naming style",
+            ],
+        }
+    }
+
+    fn configure(&mut self, config: &toml::Value) -> Result<(), String> {
+        let options: Options = parse_options(config)?;
+        if let Some(min_names) = options.min_names {
+            self.min_names = min_names;
+        }
+        if let Some(min_deviations) = options.min_deviations {
+            if !(1..=4).contains(&min_deviations) {
+                return Err(format!("min_deviations must be from 1 to 4, got {min_deviations}"));
+            }
+            self.min_deviations = min_deviations;
+        }
+        Ok(())
+    }
+
+    fn check(&self, _ctx: &RuleContext) -> Vec<Finding> {
+        // A file's names are only unusual relative to the rest of the project
+        Vec::new()
+    }
+
+    fn project(&self) -> Option<&dyn DynProjectRule> {
+        Some(self)
+    }
+}
+
+impl ProjectRule for TemplateNaming {
+    type Summary = FileNames;
+
+    fn collect(&self, ctx: &RuleContext) -> Option<FileNames> {
+        let mut names = Vec::new();
+        let mut cursor = ctx.tree.walk();
+        loop {
+            let node = cursor.node();
+            if (ctx.is(node, SemanticNode::Function) || ctx.is(node, SemanticNode::Closure))
+                && let Some(name) = function_name(ctx, node)
+            {
+                names.push(name);
+            }
+
+            if cursor.goto_first_child() {
+                continue;
+            }
+            loop {
+                if cursor.goto_next_sibling() {
+                    break;
+                }
+                if !cursor.goto_parent() {
+                    return (!names.is_empty()).then_some(FileNames {
+                        language: family(ctx.language),
+                        names,
+                    });
+                }
+            }
+        }
+    }
+
+    fn finalize(&self, files: &[ProjectFile<FileNames>]) -> Vec<Finding> {
+        let profiles: Vec<Profile> = files.iter().map(|file| Profile::of(&file.summary.names)).collect();
+        let mut totals: HashMap<Language, Profile> = HashMap::new();
+        for (file, profile) in files.iter().zip(&profiles) {
+            totals.entry(file.summary.language).or_default().add(profile);
+        }
+
+        let mut findings = Vec::new();
+        for (file, profile) in files.iter().zip(&profiles) {
+            if profile.names < self.min_names {
+                continue;
+            }
+            // The rest of the project, so a large file does not set its own baseline
+            let mut baseline = totals[&file.summary.language].clone();
+            baseline.remove(profile);
+            if baseline.names < self.min_names {
+                continue;
+            }
+            let deviations = deviations(profile, &baseline);
+            if deviations.count() >= self.min_deviations {
+                findings.push(finding(file, profile, &baseline, &deviations));
+            }
+        }
+        findings
+    }
+}
+
+/// Function names outside the project's control, such as Python's dunder
+/// methods, are left out.
+fn function_name(ctx: &RuleContext, function: tree_sitter::Node) -> Option<Name> {
+    let text = ctx.function_name(function)?;
+    if text.starts_with("__") && text.ends_with("__") {
+        return None;
+    }
+    let words = split_identifier(text);
+    if words.is_empty() {
+        return None;
+    }
+    let node = ctx.function_name_node(function)?;
+    let start = node.start_position();
+    Some(Name {
+        text: text.to_string(),
+        casing: Casing::of(text),
+        words,
+        line: start.row + 1,
+        column: start.column + 1,
+        span: node.byte_range(),
+    })
+}
+
+/// TypeScript and JavaScript share naming conventions, so they share a baseline.
+fn family(language: Language) -> Language {
+    match language {
+        Language::TypeScript | Language::Tsx => Language::JavaScript,
+        other => other,
+    }
+}
+
+fn deviations(profile: &Profile, baseline: &Profile) -> Deviations {
+    let dominant = |count: usize, total: usize| count >= MIN_TEMPLATE_NAMES && share(count, total) >= DOMINANT_SHARE;
+    let mut deviations = Deviations::default();
+    if let Some((casing, count)) = profile.top_casing()
+        && dominant(count, profile.cased)
+        && baseline.cased > 0
+        && baseline.casing_share(casing) < RARE_SHARE
+    {
+        deviations.casing = Some(casing);
+    }
+    if let Some((prefix, count)) = top_word(&profile.prefixes)
+        && dominant(count, profile.compound)
+        && baseline.compound > 0
+        && baseline.prefix_share(prefix) < RARE_SHARE
+    {
+        deviations.prefix = Some(prefix.to_string());
+    }
+    if let Some((suffix, count)) = top_word(&profile.suffixes)
+        && dominant(count, profile.compound)
+        && baseline.compound > 0
+        && baseline.suffix_share(suffix) < RARE_SHARE
+    {
+        deviations.suffix = Some(suffix.to_string());
+    }
+    let difference = (profile.mean_words() - baseline.mean_words()).abs();
+    if difference >= MIN_WORDS_DIFFERENCE && difference >= 2.0 * baseline.words_deviation() {
+        deviations.words = Some(profile.mean_words());
+    }
+    deviations
+}
+
+/// Describes `profile` along the file's own top casing, prefix and suffix, so
+/// the observed and expected profiles line up term by term.
+fn describe(profile: &Profile, file: &Profile, with_spread: bool) -> String {
+    let mut parts = Vec::new();
+    if let Some((casing, _)) = file.top_casing() {
+        parts.push(format!("{} {}", casing.label(), percent(profile.casing_share(casing))));
+    }
+    if let Some((prefix, _)) = top_word(&file.prefixes) {
+        parts.push(format!("prefix `{prefix}` {}", percent(profile.prefix_share(prefix))));
+    }
+    if let Some((suffix, _)) = top_word(&file.suffixes) {
+        parts.push(format!("suffix `{suffix}` {}", percent(profile.suffix_share(suffix))));
+    }
+    if with_spread {
+        parts.push(format!(
+            "{:.1} ± {:.1} words",
+            profile.mean_words(),
+            profile.words_deviation()
+        ));
+    } else {
+        parts.push(format!("{:.1} words", profile.mean_words()));
+    }
+    parts.join(", ")
+}
+
+fn finding(file: &ProjectFile<FileNames>, profile: &Profile, baseline: &Profile, deviations: &Deviations) -> Finding {
+    let template: Vec<&Name> = file
+        .summary
+        .names
+        .iter()
+        .filter(|name| deviations.matches(name, baseline.mean_words()))
+        .collect();
+    let first = template.first().copied().unwrap_or(&file.summary.names[0]);
+    let related = template
+        .iter()
+        .map(|name| RelatedLocation {
+            file: file.path.clone(),
+            line: name.line,
+            column: name.column,
+            span: name.span.clone(),
+            message: format!("`{}` follows the template", name.text),
+        })
+        .collect();
+
+    Finding {
+        rule_id: "",
+        message: format!(
+            "{} of {} function names follow a template the rest of the project does not use; \
+             observed: {}; expected: {}",
+            template.len(),
+            profile.names,
+            describe(profile, profile, false),
+            describe(baseline, profile, true),
+        ),
+        severity: Severity::Info,
+        file: file.path.clone(),
+        line: first.line,
+        column: first.column,
+        span: first.span.clone(),
+        suggestion: Some("Rename these functions to match how the rest of the codebase names things.".to_string()),
+        fix: None,
+        metrics: None,
+        related,
+    }
+}
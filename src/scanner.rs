@@ -2,22 +2,39 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
+use crate::engine::{FileAnalysis, RuleEngine};
+use crate::parsers::{Language, LanguageParser};
+use crate::rules::RuleContext;
+use crate::types::Finding;
 use ignore::WalkBuilder;
+use ignore::overrides::Override;
+use rayon::prelude::*;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::collections::hash_map::Entry;
 use std::path::{Path, PathBuf};
 
-const SUPPORTED_EXTENSIONS: &[&str] = &["js", "jsx", "ts", "tsx"];
-
-pub fn scan_files(path: &Path) -> Vec<PathBuf> {
+/// Collects supported source files under `path`, skipping anything matched
+/// by `.gitignore` or excluded by `overrides`.
+pub fn scan_files(path: &Path, overrides: &Override) -> Vec<PathBuf> {
     let mut files = Vec::new();
 
     if path.is_file() {
-        if has_supported_extension(path) {
+        if Language::from_path(path).is_some() && !is_excluded(path, overrides) {
             files.push(path.to_path_buf());
         }
         return files;
     }
 
-    for entry in WalkBuilder::new(path).build() {
+    let walk_overrides = overrides.clone();
+    let walker = WalkBuilder::new(path)
+        .filter_entry(move |entry| {
+            let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+            !matches_override(entry.path(), is_dir, &walk_overrides)
+        })
+        .build();
+
+    for entry in walker {
         let entry = match entry {
             Ok(e) => e,
             Err(e) => {
@@ -26,7 +43,7 @@ pub fn scan_files(path: &Path) -> Vec<PathBuf> {
             }
         };
         let path = entry.path();
-        if path.is_file() && has_supported_extension(path) {
+        if path.is_file() && Language::from_path(path).is_some() {
             files.push(path.to_path_buf());
         }
     }
@@ -35,8 +52,134 @@ pub fn scan_files(path: &Path) -> Vec<PathBuf> {
     files
 }
 
-fn has_supported_extension(path: &Path) -> bool {
-    path.extension()
-        .and_then(|ext| ext.to_str())
-        .is_some_and(|ext| SUPPORTED_EXTENSIONS.contains(&ext))
+/// Where `analyze_files` reads file contents from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Contents {
+    WorkingTree,
+    /// The staged version, so a pre-commit check sees exactly what will be
+    /// committed even when only part of a file is staged. Only the files in
+    /// the set, whose working copy differs from the index, are read through
+    /// git; the rest are identical on disk.
+    Index(HashSet<PathBuf>),
+}
+
+impl Contents {
+    fn read(&self, path: &Path) -> Result<Vec<u8>, String> {
+        match self {
+            Self::Index(unstaged) if unstaged.contains(&crate::diff::canonical(path)) => crate::diff::read_staged(path),
+            Self::WorkingTree | Self::Index(_) => std::fs::read(path).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+thread_local! {
+    // tree-sitter parsers are not thread-safe, so each worker keeps its own per language
+    static PARSERS: RefCell<HashMap<Language, Box<dyn LanguageParser>>> = RefCell::new(HashMap::new());
+}
+
+/// Findings for a set of files plus the source of each analyzed file,
+/// which reporters need to render snippets.
+pub struct Analysis {
+    pub findings: Vec<Finding>,
+    pub sources: HashMap<PathBuf, Vec<u8>>,
+}
+
+/// Reads `files` from `contents`, then parses and analyzes them on a pool of
+/// `jobs` threads (0 picks one per CPU) and runs the engine's project-level
+/// checks. Results are merged in input order, so output is identical no
+/// matter how the work was scheduled. Unreadable and unparsable files are
+/// reported and skipped, but a failing rule fails the whole analysis.
+pub fn analyze_files(
+    files: &[PathBuf],
+    engine: &RuleEngine,
+    jobs: usize,
+    contents: &Contents,
+) -> Result<Analysis, String> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .map_err(|e| format!("Failed to start worker threads: {e}"))?;
+
+    let results: Vec<Result<Option<FileAnalysis>, String>> = pool.install(|| {
+        files
+            .par_iter()
+            .map(|file_path| analyze_file(file_path, engine, contents))
+            .collect()
+    });
+
+    let mut analyzed = Vec::new();
+    for result in results {
+        match result {
+            Ok(Some(file)) => analyzed.push(file),
+            Ok(None) => {}
+            Err(e) => eprintln!("{e}"),
+        }
+    }
+
+    let errors: Vec<&String> = analyzed.iter().flat_map(|file| &file.errors).collect();
+    if !errors.is_empty() {
+        for e in &errors {
+            eprintln!("{e}");
+        }
+        return Err(format!("{} rule check(s) failed", errors.len()));
+    }
+
+    let findings = engine.finish(&mut analyzed);
+    let sources = analyzed.into_iter().map(|file| (file.path, file.source)).collect();
+    Ok(Analysis { findings, sources })
+}
+
+/// Returns `Ok(None)` for files without a parser for their extension.
+fn analyze_file(file_path: &Path, engine: &RuleEngine, contents: &Contents) -> Result<Option<FileAnalysis>, String> {
+    let source = contents
+        .read(file_path)
+        .map_err(|e| format!("Error reading {}: {e}", file_path.display()))?;
+
+    let Some(language) = Language::from_path(file_path) else {
+        return Ok(None);
+    };
+
+    PARSERS.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        let parser = match cache.entry(language) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(
+                language
+                    .parser()
+                    .map_err(|e| format!("Error initializing {} parser: {e}", language.id()))?,
+            ),
+        };
+
+        let tree = parser
+            .parse(&source)
+            .map_err(|e| {
+                format!("Parse error in {} ({}): {e}", file_path.display(), parser.language_id())
+            })?;
+
+        Ok(Some(engine.analyze(&RuleContext {
+            source: &source,
+            tree: &tree,
+            file_path,
+            language,
+            node_types: parser.node_types(),
+        })))
+    })
+}
+
+/// Checks a single file and each of its parent directories against `overrides`,
+/// mirroring what the directory walker would have pruned.
+fn is_excluded(path: &Path, overrides: &Override) -> bool {
+    path.ancestors()
+        .filter(|p| !p.as_os_str().is_empty())
+        .any(|p| matches_override(p, p != path, overrides))
+}
+
+/// Override globs are relative to the config file's directory, so match on the
+/// absolute path to stay independent of the working directory.
+fn matches_override(path: &Path, is_dir: bool, overrides: &Override) -> bool {
+    if overrides.is_empty() {
+        return false;
+    }
+    let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    overrides.matched(&path, is_dir).is_ignore()
 }
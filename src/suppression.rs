@@ -0,0 +1,260 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::parsers::SemanticNode;
+use crate::rules::RuleContext;
+use crate::tokens::comment_body;
+use crate::types::{Edit, Finding, Severity};
+use std::ops::RangeInclusive;
+use std::path::Path;
+
+pub const UNUSED_SUPPRESSION_ID: &str = "unused-suppression";
+
+const DIRECTIVES: &[&str] = &[
+    "patina-ignore-next-line",
+    "patina-ignore",
+    "patina-disable-file",
+    "patina-disable",
+    "patina-enable",
+];
+
+/// Returns true if the comment is a patina suppression directive.
+/// Rules use this to exempt directives from comment heuristics.
+pub fn is_directive(comment_text: &str) -> bool {
+    parse_directive(comment_text).is_some()
+}
+
+/// Splits a comment into its directive keyword and rule IDs.
+fn parse_directive(comment_text: &str) -> Option<(&'static str, Vec<String>)> {
+    let body = comment_body(comment_text).trim();
+    let body = body.split("--").next().unwrap_or_default();
+
+    let mut words = body.split(|c: char| c.is_whitespace() || c == ',').filter(|w| !w.is_empty());
+    let keyword = words.next()?;
+    let directive = DIRECTIVES.iter().find(|d| **d == keyword)?;
+    Some((directive, words.map(str::to_string).collect()))
+}
+
+struct Suppression {
+    directive: &'static str,
+    /// `None` suppresses every rule.
+    rules: Option<Vec<String>>,
+    /// 1-based lines; a finding matches when its line range intersects this.
+    lines: RangeInclusive<usize>,
+    span: std::ops::Range<usize>,
+    line: usize,
+    column: usize,
+    used: Vec<bool>,
+}
+
+impl Suppression {
+    fn rule_index(&self, rule_id: &str) -> Option<usize> {
+        match &self.rules {
+            None => Some(0),
+            Some(rules) => rules.iter().position(|r| r == rule_id),
+        }
+    }
+}
+
+/// Inline suppression comments.
+///
+/// - `// patina-ignore-next-line slop-001` — findings covering the next line
+/// - `code(); // patina-ignore slop-004` — findings covering the same line
+/// - `/* patina-disable slop-003 */ … /* patina-enable */` — findings starting in the range
+/// - `// patina-disable-file` — every finding in the file
+///
+/// Omitting rule IDs applies the directive to all rules. Anything after `--` is
+/// a free-form justification. Directives that never match a finding are
+/// reported as `unused-suppression` findings.
+pub struct Suppressions {
+    entries: Vec<Suppression>,
+}
+
+impl Suppressions {
+    /// Collects every directive comment in the tree.
+    pub fn parse(ctx: &RuleContext) -> Self {
+        let mut comments = Vec::new();
+        let mut cursor = ctx.tree.walk();
+        loop {
+            let node = cursor.node();
+            if ctx.is(node, SemanticNode::Comment) {
+                comments.push(node);
+            }
+
+            if cursor.goto_first_child() {
+                continue;
+            }
+            if cursor.goto_next_sibling() {
+                continue;
+            }
+            loop {
+                if !cursor.goto_parent() {
+                    return Self::from_comments(&comments, ctx.source);
+                }
+                if cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn from_comments(comments: &[tree_sitter::Node], source: &[u8]) -> Self {
+        let last_line = source.iter().filter(|&&b| b == b'\n').count() + 1;
+        let mut entries: Vec<Suppression> = Vec::new();
+        // Indices into `entries` of `patina-disable` ranges still waiting for an enable
+        let mut open: Vec<usize> = Vec::new();
+
+        for node in comments {
+            let Ok(text) = node.utf8_text(source) else {
+                continue;
+            };
+            let Some((directive, ids)) = parse_directive(text) else {
+                continue;
+            };
+            let start = node.start_position();
+            let line = start.row + 1;
+
+            if directive == "patina-enable" {
+                open.retain(|&i| {
+                    let closes = match &entries[i].rules {
+                        Some(rules) if !ids.is_empty() => rules.iter().any(|r| ids.contains(r)),
+                        _ => true,
+                    };
+                    if closes {
+                        entries[i].lines = *entries[i].lines.start()..=line;
+                    }
+                    !closes
+                });
+                continue;
+            }
+
+            let lines = match directive {
+                "patina-ignore-next-line" => line + 1..=line + 1,
+                "patina-ignore" => line..=node.end_position().row + 1,
+                "patina-disable-file" => 1..=last_line,
+                _ => line..=last_line,
+            };
+            if directive == "patina-disable" {
+                open.push(entries.len());
+            }
+
+            let rules = if ids.is_empty() { None } else { Some(ids) };
+            let used = vec![false; rules.as_ref().map_or(1, Vec::len)];
+            entries.push(Suppression {
+                directive,
+                rules,
+                lines,
+                span: node.byte_range(),
+                line,
+                column: start.column + 1,
+                used,
+            });
+        }
+
+        Self { entries }
+    }
+
+    /// Returns true if any directive covers the finding, marking it as used.
+    pub fn suppresses(&mut self, finding: &Finding, source: &[u8]) -> bool {
+        let start = finding.line;
+        let end = start
+            + source
+                .get(finding.span.clone())
+                .map_or(0, |s| s.iter().filter(|&&b| b == b'\n').count());
+
+        let mut suppressed = false;
+        for entry in &mut self.entries {
+            let intersects = if entry.directive == "patina-disable" {
+                entry.lines.contains(&start)
+            } else {
+                start <= *entry.lines.end() && *entry.lines.start() <= end
+            };
+            if !intersects {
+                continue;
+            }
+            if let Some(i) = entry.rule_index(finding.rule_id) {
+                entry.used[i] = true;
+                suppressed = true;
+            }
+        }
+        suppressed
+    }
+
+    /// Builds findings for directives that suppressed nothing. Rule IDs that are
+    /// not in `active_rules` are skipped since their findings were never produced.
+    pub fn unused(&self, source: &[u8], file_path: &Path, active_rules: &[&str]) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for entry in &self.entries {
+            let unused: Vec<&str> = match &entry.rules {
+                None if !entry.used[0] => vec!["all rules"],
+                None => continue,
+                Some(rules) => rules
+                    .iter()
+                    .zip(&entry.used)
+                    .filter(|(rule, used)| !**used && active_rules.contains(&rule.as_str()))
+                    .map(|(rule, _)| rule.as_str())
+                    .collect(),
+            };
+            if unused.is_empty() {
+                continue;
+            }
+
+            findings.push(Finding {
+                rule_id: UNUSED_SUPPRESSION_ID,
+                message: format!(
+                    "Unused Suppression: `{}` for {} matched no findings",
+                    entry.directive,
+                    unused.join(", ")
+                ),
+                severity: Severity::Warn,
+                file: file_path.to_path_buf(),
+                line: entry.line,
+                column: entry.column,
+                span: entry.span.clone(),
+                suggestion: Some(
+                    "Remove this suppression — it no longer silences any finding.".to_string(),
+                ),
+                fix: Some(Edit::delete_lines(source, entry.span.clone())),
+                metrics: None,
+                related: Vec::new(),
+            });
+        }
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_directive_with_rules_and_reason() {
+        let (directive, ids) =
+            parse_directive("// patina-ignore-next-line slop-001, slop-003 -- generated file")
+                .unwrap();
+        assert_eq!(directive, "patina-ignore-next-line");
+        assert_eq!(ids, vec!["slop-001", "slop-003"]);
+    }
+
+    #[test]
+    fn test_parse_block_directive() {
+        let (directive, ids) = parse_directive("/* patina-disable slop-003 */").unwrap();
+        assert_eq!(directive, "patina-disable");
+        assert_eq!(ids, vec!["slop-003"]);
+    }
+
+    #[test]
+    fn test_parse_hash_directive() {
+        let (directive, ids) = parse_directive("# patina-ignore slop-004").unwrap();
+        assert_eq!(directive, "patina-ignore");
+        assert_eq!(ids, vec!["slop-004"]);
+    }
+
+    #[test]
+    fn test_non_directives() {
+        assert!(!is_directive("// patina is a static analysis tool"));
+        assert!(!is_directive("// see patina-ignore docs"));
+        assert!(!is_directive("// patina-ignored"));
+    }
+}
@@ -53,17 +53,34 @@ const STOP_WORDS: &[&str] = &[
     "the", "a", "an", "this", "that", "to", "of", "in", "for", "is", "it", "be", "as", "with",
 ];
 
+/// Returns the text of a comment with its delimiters removed: `//`, `///`,
+/// `//!`, `#`, `/* */`, `/*! */`, or the quotes (and any `r`/`b`/`u`/`f` prefix) of a Python
+/// docstring. `*` prefixes on inner lines are left for the caller.
+pub fn comment_body(comment_text: &str) -> &str {
+    let trimmed = comment_text.trim();
+    if let Some(line) = ["///", "//!", "//", "#"]
+        .iter()
+        .find_map(|marker| trimmed.strip_prefix(marker))
+    {
+        return line;
+    }
+    if let Some(block) = trimmed.strip_prefix("/*!").or_else(|| trimmed.strip_prefix("/*")) {
+        return block.strip_suffix("*/").unwrap_or(block);
+    }
+
+    let unprefixed = trimmed.trim_start_matches(['r', 'R', 'b', 'B', 'u', 'U', 'f', 'F']);
+    for quote in ["\"\"\"", "'''", "\"", "'"] {
+        if let Some(inner) = unprefixed.strip_prefix(quote).and_then(|s| s.strip_suffix(quote)) {
+            return inner;
+        }
+    }
+    trimmed
+}
+
 /// Strip comment markers and extract meaningful tokens from comment text.
-/// Removes `//`, `/*`, `*/`, `*` line prefixes, stop words; stems and lowercases.
+/// Removes comment delimiters, `*` line prefixes, stop words; stems and lowercases.
 pub fn extract_comment_tokens(comment_text: &str) -> Vec<String> {
-    let cleaned = comment_text
-        .trim()
-        .strip_prefix("//")
-        .or_else(|| comment_text.trim().strip_prefix("/*"))
-        .unwrap_or(comment_text.trim());
-    let cleaned = cleaned.strip_suffix("*/").unwrap_or(cleaned);
-
-    cleaned
+    comment_body(comment_text)
         .lines()
         .flat_map(|line| {
             let line = line.trim().strip_prefix('*').unwrap_or(line.trim()).trim();
@@ -148,6 +165,15 @@ mod tests {
         assert!(!tokens.iter().any(|t| t == "the"));
     }
 
+    #[test]
+    fn test_comment_body_strips_delimiters() {
+        assert_eq!(comment_body("// Set the name").trim(), "Set the name");
+        assert_eq!(comment_body("# Set the name").trim(), "Set the name");
+        assert_eq!(comment_body("//! Set the name").trim(), "Set the name");
+        assert_eq!(comment_body("/* Set the name */").trim(), "Set the name");
+        assert_eq!(comment_body("r\"\"\"Set the name\"\"\"").trim(), "Set the name");
+    }
+
     #[test]
     fn test_extract_code_tokens() {
         let tokens = extract_code_tokens(&["setName", "userName"]);
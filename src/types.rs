@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: GPL-3.0-only
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::ops::Range;
 use std::path::PathBuf;
 
@@ -17,10 +17,115 @@ pub struct Finding {
     pub span: Range<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub suggestion: Option<String>,
+    /// Machine-applicable fix used by `patina fix`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<Edit>,
+    /// Measurements behind a function-level finding, for charting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<FunctionMetrics>,
+    /// Other code the finding is about, such as the rest of a group of
+    /// near-identical functions.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<RelatedLocation>,
 }
 
-#[allow(dead_code)]
+/// A secondary location attached to a finding.
+#[derive(Debug, Clone, Serialize)]
+pub struct RelatedLocation {
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    pub span: Range<usize>,
+    pub message: String,
+}
+
+/// Size and complexity of one function, method or closure.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct FunctionMetrics {
+    pub lines: usize,
+    pub statements: usize,
+    pub complexity: usize,
+    pub parameters: usize,
+}
+
+/// Replaces the bytes in `span` with `replacement`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Edit {
+    pub span: Range<usize>,
+    pub replacement: String,
+}
+
+impl Edit {
+    /// Deletes `span`, widened so the file is left tidy:
+    /// - if `span` is the only thing on its lines, the whole lines go, along with
+    ///   one blank line when that would otherwise leave two in a row;
+    /// - if code precedes it on the line (a trailing comment), the whitespace
+    ///   between the code and `span` goes too;
+    /// - if code follows it on the line, the whitespace after `span` goes.
+    pub fn delete_lines(source: &[u8], span: Range<usize>) -> Self {
+        let line_start = source[..span.start]
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |i| i + 1);
+        let line_end = source[span.end..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(source.len(), |i| span.end + i);
+
+        let is_blank = |bytes: &[u8]| bytes.iter().all(|b| b.is_ascii_whitespace());
+        let code_before = !is_blank(&source[line_start..span.start]);
+        let code_after = !is_blank(&source[span.end..line_end]);
+
+        let span = if code_before {
+            let start = line_start
+                + source[line_start..span.start]
+                    .iter()
+                    .rposition(|b| !b.is_ascii_whitespace())
+                    .map_or(0, |i| i + 1);
+            start..span.end
+        } else if code_after {
+            let end = span.end
+                + source[span.end..line_end]
+                    .iter()
+                    .position(|b| !b.is_ascii_whitespace())
+                    .unwrap_or(0);
+            span.start..end
+        } else {
+            // Consume the trailing newline so the line disappears entirely
+            let mut end = (line_end + 1).min(source.len());
+            let blank_before = line_start == 0 || Self::previous_line_is_blank(source, line_start);
+            let next_end = source[end..]
+                .iter()
+                .position(|&b| b == b'\n')
+                .map(|i| end + i + 1);
+            if let Some(next_end) = next_end
+                && blank_before
+                && is_blank(&source[end..next_end])
+            {
+                end = next_end;
+            }
+            line_start..end
+        };
+
+        Self {
+            span,
+            replacement: String::new(),
+        }
+    }
+
+    fn previous_line_is_blank(source: &[u8], line_start: usize) -> bool {
+        let prev_start = source[..line_start - 1]
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |i| i + 1);
+        source[prev_start..line_start - 1]
+            .iter()
+            .all(|b| b.is_ascii_whitespace())
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Severity {
     Error,
@@ -28,9 +133,8 @@ pub enum Severity {
     Info,
 }
 
-#[allow(dead_code)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
-#[serde(rename_all = "lowercase")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum Category {
     Slop,
     Bloat,
@@ -47,3 +151,72 @@ impl std::fmt::Display for Severity {
         }
     }
 }
+
+#[cfg(test)]
+impl Finding {
+    /// A `slop-001` finding in `app.js` spanning the first `needle` in `source`.
+    pub fn test_at(source: &str, needle: &str) -> Self {
+        let start = source.find(needle).unwrap();
+        Self {
+            rule_id: "slop-001",
+            message: String::new(),
+            severity: Severity::Warn,
+            file: PathBuf::from("app.js"),
+            line: source[..start].matches('\n').count() + 1,
+            column: 1,
+            span: start..start + needle.len(),
+            suggestion: None,
+            fix: None,
+            metrics: None,
+            related: Vec::new(),
+        }
+    }
+
+    /// Adds a fix deleting the finding's span from `source`.
+    pub fn with_fix(mut self, source: &str) -> Self {
+        self.fix = Some(Edit::delete_lines(source.as_bytes(), self.span.clone()));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn delete(source: &str, needle: &str) -> String {
+        let start = source.find(needle).unwrap();
+        let edit = Edit::delete_lines(source.as_bytes(), start..start + needle.len());
+        let mut fixed = source.to_string();
+        fixed.replace_range(edit.span, &edit.replacement);
+        fixed
+    }
+
+    #[test]
+    fn test_delete_whole_line_with_indentation() {
+        assert_eq!(
+            delete("if (x) {\n    // Set the name\n    setName(n);\n}\n", "// Set the name"),
+            "if (x) {\n    setName(n);\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_delete_collapses_double_blank_lines() {
+        assert_eq!(
+            delete("a();\n\n// x();\n\nb();\n", "// x();"),
+            "a();\n\nb();\n"
+        );
+    }
+
+    #[test]
+    fn test_delete_trailing_comment_keeps_code() {
+        assert_eq!(
+            delete("run(); // Run it now\nnext();\n", "// Run it now"),
+            "run();\nnext();\n"
+        );
+    }
+
+    #[test]
+    fn test_delete_leading_block_comment_keeps_code() {
+        assert_eq!(delete("/* old */ run();\n", "/* old */"), "run();\n");
+    }
+}
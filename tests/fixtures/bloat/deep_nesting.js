@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+function notifyUsers(users) {
+  for (const user of users) {
+    if (user.active) {
+      for (const device of user.devices) {
+        if (device.enabled) {
+          // expect: bloat-001
+          if (device.token) {
+            try {
+              push(device.token);
+            } catch (err) {
+              retry(device);
+            }
+          }
+        }
+      }
+    }
+  }
+}
+
+function syncAll(accounts) {
+  accounts.forEach((account) => {
+    if (account.linked) {
+      account.sources.forEach((source) => {
+        if (source.stale) {
+          // expect: bloat-001
+          source.items.forEach((item) => refresh(item));
+        }
+      });
+    }
+  });
+}
+
+function classify(value) {
+  if (value < 0) {
+    return "negative";
+  } else if (value === 0) {
+    return "zero";
+  } else if (value < 10) {
+    return "small";
+  } else if (value < 100) {
+    return "medium";
+  } else if (value < 1000) {
+    return "large";
+  } else {
+    return "huge";
+  }
+}
+
+function route(request) {
+  switch (request.method) {
+    case "GET":
+      for (const handler of handlers) {
+        if (handler.matches(request)) {
+          while (handler.busy) {
+            wait();
+          }
+        }
+      }
+      break;
+    default:
+      reject(request);
+  }
+}
+
+class Importer {
+  run(rows) {
+    for (const row of rows) {
+      if (row.valid) {
+        while (row.pending) {
+          if (row.retry) {
+            // expect: bloat-001
+            for (const cell of row.cells) {
+              save(cell);
+            }
+          }
+        }
+      }
+    }
+  }
+}
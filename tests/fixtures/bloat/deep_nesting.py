@@ -0,0 +1,30 @@
+# SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+#
+# SPDX-License-Identifier: GPL-3.0-only
+
+
+def import_rows(rows):
+    for row in rows:
+        if row.valid:
+            with row.lock:
+                while row.pending:
+                    if row.retry:
+                        # expect: bloat-001
+                        for cell in row.cells:
+                            save(cell)
+
+
+def classify(value):
+    if value < 0:
+        return "negative"
+    elif value == 0:
+        return "zero"
+    elif value < 10:
+        return "small"
+    else:
+        for limit in LIMITS:
+            if value < limit:
+                try:
+                    return label(limit)
+                except KeyError:
+                    return "unknown"
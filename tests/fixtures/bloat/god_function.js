@@ -0,0 +1,49 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+// expect: bloat-002
+function createUser(name, email, password, role, team, invitedBy) {
+  return users.insert({ name, email, password, role, team, invitedBy });
+}
+
+// expect: bloat-002
+const validateOrder = (order) => {
+  if (!order) return false;
+  if (!order.id || !order.customer) return false;
+  if (order.items.length === 0 && !order.draft) return false;
+  for (const item of order.items) {
+    if (item.quantity <= 0 || item.price < 0) return false;
+    if (item.discount && item.discount > item.price) return false;
+  }
+  switch (order.status) {
+    case "open":
+    case "pending":
+      break;
+    case "closed":
+      return order.closedAt ?? false;
+    default:
+      return false;
+  }
+  try {
+    return order.total > 0 ? checkTotal(order) : false;
+  } catch (err) {
+    return false;
+  }
+};
+
+class OrderService {
+  // expect: bloat-002
+  update(id, status, note, actor, reason, notify) {
+    return this.repo.update(id, { status, note, actor, reason, notify });
+  }
+
+  cancel(id) {
+    return this.update(id, "cancelled", "", null, "", true);
+  }
+}
+
+function summarize(order) {
+  const total = order.items.reduce((sum, item) => sum + item.price, 0);
+  return order.currency ? format(total, order.currency) : String(total);
+}
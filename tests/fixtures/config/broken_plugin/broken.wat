@@ -0,0 +1,21 @@
+;; A plugin whose check always traps, to test that plugin failures fail the scan.
+(module
+  (memory (export "memory") 1)
+  (data (i32.const 0) "[{\"id\":\"broken-001\",\"name\":\"Broken Plugin Rule\",\"description\":\"Traps on every file\",\"severity\":\"warn\",\"category\":\"slop\"}]")
+  (global $heap (mut i32) (i32.const 4096))
+
+  (func (export "patina_abi_version") (result i32)
+    (i32.const 1))
+
+  (func (export "patina_alloc") (param $size i32) (result i32)
+    (local $ptr i32)
+    (local.set $ptr (global.get $heap))
+    (global.set $heap (i32.add (global.get $heap) (local.get $size)))
+    (drop (memory.grow (i32.add (i32.shr_u (local.get $size) (i32.const 16)) (i32.const 1))))
+    (local.get $ptr))
+
+  (func (export "patina_rules") (result i64)
+    (i64.const 121))
+
+  (func (export "patina_check") (param $rule i32) (param $input i32) (param $len i32) (result i64)
+    (unreachable)))
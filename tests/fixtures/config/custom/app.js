@@ -0,0 +1,15 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+// expect: slop-001
+// Create the new user account
+createNewUserAccount();
+
+// Not flagged — only three meaningful words, below min_comment_words = 4
+// Set user name
+user.setUserName(name);
+
+// Not flagged — slop-003 is disabled
+// Basically, this just works
+run();
@@ -0,0 +1,30 @@
+;; A minimal patina plugin: one rule that reports the first word of every
+;; JavaScript file. Build it with `wat2wasm example.wat -o example.wasm`.
+(module
+  (memory (export "memory") 1)
+
+  ;; Rule metadata, returned by `patina_rules`.
+  (data (i32.const 0) "[{\"id\":\"example-001\",\"name\":\"Example Plugin Rule\",\"description\":\"Reports the first word of a file\",\"severity\":\"info\",\"category\":\"slop\",\"languages\":[\"javascript\"]}]")
+  ;; Findings, returned by `patina_check`.
+  (data (i32.const 1024) "[{\"start_byte\":0,\"end_byte\":6,\"message\":\"first word of the file\"}]")
+
+  ;; Inputs are placed after the static data; each call gets a fresh instance,
+  ;; so a bump allocator never needs to free.
+  (global $heap (mut i32) (i32.const 4096))
+
+  (func (export "patina_abi_version") (result i32)
+    (i32.const 1))
+
+  (func (export "patina_alloc") (param $size i32) (result i32)
+    (local $ptr i32)
+    (local.set $ptr (global.get $heap))
+    (global.set $heap (i32.add (global.get $heap) (local.get $size)))
+    (drop (memory.grow (i32.add (i32.shr_u (local.get $size) (i32.const 16)) (i32.const 1))))
+    (local.get $ptr))
+
+  ;; Results are packed as (pointer << 32) | length.
+  (func (export "patina_rules") (result i64)
+    (i64.const 163))
+
+  (func (export "patina_check") (param $rule i32) (param $input i32) (param $len i32) (result i64)
+    (i64.or (i64.shl (i64.const 1024) (i64.const 32)) (i64.const 66))))
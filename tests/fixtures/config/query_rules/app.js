@@ -0,0 +1,21 @@
+import { notify } from "./notify.js";
+
+export function remove(item) {
+  if (!confirm(`Delete ${item.name}?`)) {
+    return;
+  }
+  item.remove();
+  alert("Deleted");
+  notify("Deleted");
+}
+
+export function load(items) {
+  // patina-ignore-next-line house-001 -- shown before the toast service loads
+  alert("Loading");
+  return items.map((item) => {
+    const label = item.name.trim();
+    const price = item.price.toFixed(2);
+    const stock = item.stock > 0 ? "in stock" : "sold out";
+    return `${label}: ${price} (${stock})`;
+  });
+}
@@ -0,0 +1,112 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+function loadSettings(path) {
+  try {
+    return readSettings(path);
+  // expect: cult-001
+  } catch (err) {}
+}
+
+function saveDraft(draft) {
+  try {
+    store.put(draft);
+  // expect: cult-001
+  } catch (err) {
+    // TODO: handle this
+  }
+}
+
+function syncAccount(account) {
+  try {
+    api.sync(account);
+  // expect: cult-001
+  } catch (err) {
+    console.error("sync failed", err);
+  }
+}
+
+function sendReport(report) {
+  try {
+    mailer.send(report);
+  // expect: cult-001
+  } catch (err) {
+    this.logger.warn(err);
+    console.log(err.stack);
+  }
+}
+
+fetchUsers()
+  // expect: cult-001
+  .catch(() => {});
+
+fetchOrders()
+  // expect: cult-001
+  .catch((err) => console.error(err));
+
+fetchInvoices()
+  // expect: cult-001
+  .catch(console.error);
+
+fetchPayments()
+  // expect: cult-001
+  .catch(function (err) {
+  });
+
+function parseConfig(text) {
+  try {
+    return JSON.parse(text);
+  } catch (err) {
+    throw new ConfigError("invalid config", { cause: err });
+  }
+}
+
+function readCache(key) {
+  try {
+    return cache.get(key);
+  } catch (err) {
+    console.warn(err);
+    return null;
+  }
+}
+
+function removeTempFile(path) {
+  try {
+    fs.unlinkSync(path);
+  } catch (err) {
+    // Intentionally ignored: the file may already be gone
+  }
+}
+
+function closeSocket(socket) {
+  try {
+    socket.close();
+  } catch { // ignore
+  }
+}
+
+function reloadConfig() {
+  try {
+    config.reload();
+    // expect: cult-001
+  } catch (err) {
+    // unexpected failure, TODO handle properly
+  }
+}
+
+function flushCache(cache) {
+  try {
+    cache.flush();
+    // expect: cult-001
+  } catch (err) {
+    // don't ignore this once the cache is persistent
+  }
+}
+
+fetchProfile().catch(() => null);
+
+fetchAvatar().catch((err) => {
+  metrics.increment("avatar.failed");
+  return placeholder;
+});
@@ -0,0 +1,52 @@
+# SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+#
+# SPDX-License-Identifier: GPL-3.0-only
+
+import logging
+
+logger = logging.getLogger(__name__)
+
+
+def load_settings(path):
+    try:
+        return read_settings(path)
+    # expect: cult-001
+    except OSError:
+        pass
+
+
+def sync_account(account):
+    try:
+        api.sync(account)
+    # expect: cult-001
+    except Exception as err:
+        logger.exception(err)
+
+
+def send_report(report):
+    try:
+        mailer.send(report)
+    # expect: cult-001
+    except Exception as err:
+        print("send failed:", err)
+
+
+def parse_config(text):
+    try:
+        return json.loads(text)
+    except ValueError as err:
+        raise ConfigError("invalid config") from err
+
+
+def read_cache(key):
+    try:
+        return cache[key]
+    except KeyError:
+        return None
+
+
+def remove_temp_file(path):
+    try:
+        os.remove(path)
+    except FileNotFoundError:  # already gone, safe to ignore
+        pass
@@ -0,0 +1,35 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+function checkout(cart) {
+  // expect: slop-007
+  console.log("cart", cart);
+  // expect: slop-007
+  debugger;
+  const total = cart.items.reduce((sum, item) => sum + item.price, 0);
+  if (total === 0) {
+    // expect: slop-007
+    alert("Your cart is empty");
+    return null;
+  }
+  // expect: slop-007
+  cart.items.forEach((item) => console.debug(item));
+  // expect: slop-007
+  window.alert("Processing");
+  return total;
+}
+
+function reportFailure(err) {
+  console.error("checkout failed", err);
+  console.warn("retrying");
+  console.info("retry scheduled");
+}
+
+function count(items) {
+  // expect: slop-007
+  if (!items) console.log("no items");
+  // expect: slop-007
+  if (items.length > 100) debugger;
+  return items.length;
+}
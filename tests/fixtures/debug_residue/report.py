@@ -0,0 +1,9 @@
+# SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+#
+# SPDX-License-Identifier: GPL-3.0-only
+
+# Python objects that happen to share names with browser debugging APIs
+def report(console, page):
+    console.log("report ready")
+    page.alert("done")
+    alert("done")
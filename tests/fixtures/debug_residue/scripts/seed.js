@@ -0,0 +1,6 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+const users = seedUsers();
+console.log(`seeded ${users.length} users`);
@@ -0,0 +1,16 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+// const legacy = loadLegacy();
+// legacy.migrate();
+// legacy.close();
+export function migrate(store) {
+  console.log("migrating", store);
+  return store.migrate();
+}
+
+export function close(store) {
+  console.log("closing", store);
+  store.close();
+}
@@ -0,0 +1,8 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+export function open(store) {
+  console.log("opening", store);
+  return store.open();
+}
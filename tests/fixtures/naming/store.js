@@ -0,0 +1,21 @@
+const items = new Map();
+
+export function addItem(item) {
+  items.set(item.id, item);
+}
+
+export function removeItem(id) {
+  items.delete(id);
+}
+
+export function findItem(id) {
+  return items.get(id);
+}
+
+export function countItems() {
+  return items.size;
+}
+
+export function clearItems() {
+  items.clear();
+}
@@ -0,0 +1,25 @@
+import { addItem, removeItem } from "./store.js";
+
+export function handleAddButtonClick(event) {
+  addItem(event.target.dataset);
+}
+
+export function handleRemoveButtonClick(event) {
+  removeItem(event.target.dataset.id);
+}
+
+export function handleResetButtonClick(event) {
+  event.preventDefault();
+}
+
+export function handleSubmitButtonClick(event) {
+  event.target.form.submit();
+}
+
+export function processFormData(form) {
+  return Object.fromEntries(new FormData(form));
+}
+
+export function processEventData(event) {
+  return { type: event.type, target: event.target.id };
+}
@@ -0,0 +1,46 @@
+# SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+#
+# SPDX-License-Identifier: GPL-3.0-only
+
+"""Account helpers used by the signup flow."""
+
+import logging
+
+
+def create_user(name, email):
+    # expect: slop-002
+    """Wait, this should validate the email before creating the account."""
+    user = User()
+    # expect: slop-001
+    # Set the user name
+    user.set_user_name(name)
+
+    # expect: slop-002
+    # Actually, the email lookup has to happen first
+    existing = find_user_by_email(email)
+
+    # expect: slop-003
+    # Basically, we just really want to quickly save the user here
+    save(user)
+
+    # expect: slop-004
+    # old = legacy_lookup(email)
+    # if old:
+    #     merge(old, user)
+    return user
+
+
+def delete_user(user):
+    # expect: slop-005
+    # Here we remove the user and all of their sessions
+    sessions.purge(user)
+
+    # expect: slop-004
+    # print(user)
+
+    # Deletion is soft: rows are kept for 30 days for support requests
+    user.deleted = True
+    # TODO(alice): move retention into config
+    # patina-ignore-next-line slop-004 -- kept until the migration script lands
+    # import legacy_accounts
+    return user
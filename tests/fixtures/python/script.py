@@ -0,0 +1,15 @@
+#!/usr/bin/env python3
+# -*- coding: utf-8 -*-
+# SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+#
+# SPDX-License-Identifier: GPL-3.0-only
+
+import sys
+
+
+def main(args):
+    return len(args)
+
+
+if __name__ == "__main__":
+    sys.exit(main(sys.argv[1:]))
@@ -0,0 +1,25 @@
+# SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+#
+# SPDX-License-Identifier: GPL-3.0-only
+
+# expect: slop-006
+###############################
+# HELPERS
+###############################
+
+
+def add(a, b):
+    return a + b
+
+
+# expect: slop-006
+# ===== Validation =====
+
+
+def is_positive(n):
+    return n > 0
+
+
+# Positive amounts are charges; negative amounts are refunds.
+def sign(n):
+    return 1 if n >= 0 else -1
@@ -0,0 +1,47 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Account helpers used by the signup flow.
+
+use std::collections::HashMap;
+
+/// Creates the user and stores it in the registry.
+///
+/// ```
+/// let user = create_user("ada", &mut registry);
+/// assert_eq!(user.name, "ada");
+/// ```
+pub fn create_user(name: &str, registry: &mut HashMap<String, User>) -> User {
+    let mut user = User::default();
+    // expect: slop-001
+    // Set the user name
+    user.set_user_name(name);
+
+    // expect: slop-002
+    // Actually, the registry lookup has to happen first
+    let existing = registry.get(name);
+
+    // expect: slop-003
+    // Basically, we just really want to quickly store the user here
+    registry.insert(name.to_string(), user.clone());
+
+    // expect: slop-004
+    // let x = foo();
+
+    // Names are unique per tenant, so the insert above cannot clobber another user
+    user
+}
+
+pub fn delete_user(user: &mut User) {
+    // expect: slop-005
+    // Here we remove the user and all of their sessions
+    user.sessions.clear();
+
+    // expect: slop-004
+    // if user.is_admin() {
+    //     audit(user);
+    // }
+    /* Deletion is soft: rows are kept for 30 days */
+    user.deleted = true;
+}
@@ -44,3 +44,7 @@ function safeGet(obj, key) {
     if (!obj) return null;
     return obj[key];
 }
+
+// This SHOULD trigger — a leading `!` only marks a shebang in Python
+// expect: slop-004
+// !isReady && start();
@@ -0,0 +1,73 @@
+// Annotations for findings on tag lines sit inside the doc block, on the
+// line before the tag; the drift rule ignores description text.
+
+/**
+ * Sends a message to a user.
+ * // expect: slop-009
+ * @param {string} userId - Recipient
+ * @param {string} text - Message body
+ */
+function send(
+  // expect: slop-009
+  recipientId: string,
+  text: string,
+  // expect: slop-009
+  retries?: number,
+) {
+  queue.push({ recipientId, text, retries });
+}
+
+/**
+ * Moves the cursor by an offset.
+ * @param {number} dy - Vertical offset
+ * // expect: slop-009
+ * @param {number} dx - Horizontal offset
+ * @param {Object} options - Movement options
+ * @param {boolean} options.animate - Animate the move
+ * // expect: slop-009
+ * @param {number} options.speed - Pixels per frame
+ * @param {...string} tags - Labels for the undo history
+ * // expect: slop-009
+ * @returns {Point} The new position
+ * // expect: slop-009
+ * @throws {RangeError} If the offsets are not finite
+ */
+function move(dx: number, dy: number, { animate, duration = 200 }: MoveOptions, ...tags: string[]) {
+  cursor.x += dx;
+  cursor.y += dy;
+}
+
+/**
+ * Scales a size.
+ * @param {Size} size - Width and height in pixels
+ * @param {number} [factor=2] - Multiplier, at least 1
+ * @param {Object} opts - Rounding settings
+ * @param {boolean} opts.round - Round to whole pixels
+ * @returns {Size} A new size; `size` is not modified
+ * @throws {RangeError} If `factor` is below 1
+ */
+export const scale = (size: Size, factor = 2, { round }: ScaleOptions = {}): Size => {
+  if (factor < 1) {
+    throw new RangeError("factor must be at least 1");
+  }
+  return round ? roundSize(size, factor) : { w: size.w * factor, h: size.h * factor };
+};
+
+class Queue {
+  /**
+   * Yields every pending item.
+   * @param {boolean} [includeFailed] - Also yield items that failed
+   * @returns {Iterable<Item>} Pending items, oldest first
+   */
+  *pending(includeFailed?: boolean) {
+    yield* this.items.filter((item) => includeFailed || !item.failed);
+  }
+
+  /**
+   * Drops every item.
+   * @returns {void}
+   */
+  clear() {
+    this.items = [];
+  }
+}
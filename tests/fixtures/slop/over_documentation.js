@@ -0,0 +1,79 @@
+// Each finding sits on the first line of its doc block so the
+// expect annotations can precede it.
+
+// expect: slop-008
+/** @param {string} userName - The user name */
+function setUserName(userName) {
+  this.userName = userName.trim();
+}
+
+// expect: slop-008
+/** @returns {void} */
+export function resetCache() {
+  cache.clear();
+}
+
+// expect: slop-008
+/** Gets the total.
+ *
+ * The total is computed when items are added, so reading it is cheap
+ * and can be done as often as needed.
+ */
+function getTotal() {
+  return this.total;
+}
+
+// expect: slop-008
+/** Parse the config. */
+const parseConfig = (text) => JSON.parse(text, reviveDates);
+
+/**
+ * Stores the display name shown in the header.
+ * @param {string} userName - Surrounding whitespace is trimmed
+ */
+function setDisplayName(userName) {
+  this.displayName = userName.trim();
+}
+
+/**
+ * Looks up a user by id.
+ * @param {number} id - Database primary key, not the public handle
+ */
+function findUser(id, callback) {
+  if (!id) {
+    return;
+  }
+  db.get(id, callback);
+}
+
+/** @returns {undefined} */
+function firstOrUndefined(items) {
+  return items[0];
+}
+
+/**
+ * Total of all line items, in cents.
+ *
+ * Kept as an integer to avoid floating-point rounding in invoices.
+ */
+function getTotalCents(currency) {
+  return this.totals[currency];
+}
+
+/**
+ * Parse the config.
+ *
+ * Dates in ISO 8601 format are revived as `Date` objects.
+ */
+function parseConfigFile(path) {
+  return parseConfig(read(path));
+}
+
+/**
+ * @param {string} name - The user's name
+ * // expect: slop-008
+ * @returns {void}
+ */
+function setName(name) {
+  this.name = name;
+}
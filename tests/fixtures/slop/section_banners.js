@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+// expect: slop-006
+// ========== HELPERS ==========
+
+function add(a, b) {
+  return a + b;
+}
+
+// expect: slop-006
+// ------------------------------
+// Validation
+// ------------------------------
+
+function isPositive(n) {
+  return n > 0;
+}
+
+// expect: slop-006
+/* ------------------------------ */
+
+// expect: slop-006
+/******************************
+ * FORMATTING
+ ******************************/
+
+function format(n) {
+  return n.toFixed(2);
+}
+
+// expect: slop-006
+// Exports ----------------------
+
+/**
+ * Returns the sum of the cart's line items, in cents.
+ */
+function cartTotal(cart) {
+  return cart.items.reduce((sum, item) => sum + item.price, 0);
+}
+
+// #region Parsing
+function parse(text) {
+  return JSON.parse(text);
+}
+// #endregion
+
+// Totals are rounded to cents before tax is applied
+// so that refunds match the original receipt.
+function roundCents(n) {
+  return Math.round(n * 100) / 100;
+}
+
+// --- note: this runs before the cache warms up
+const ready = false; // ==========
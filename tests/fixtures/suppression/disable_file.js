@@ -0,0 +1,11 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+// patina-disable-file slop-001
+
+// Set the user name
+user.setUserName(name);
+
+// Create the new user account
+createNewUserAccount();
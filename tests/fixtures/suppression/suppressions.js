@@ -0,0 +1,41 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+// patina-ignore-next-line slop-001 -- kept for the onboarding walkthrough
+// Set the user name
+user.setUserName(name);
+
+// Trailing suppression on the closing line of a commented-out block
+/*
+const legacy = loadLegacy();
+legacy.migrate();
+*/ // patina-ignore slop-004
+
+/* patina-disable slop-003 */
+// Basically, this just works
+// Simply put, it is very quick
+
+// A range for a different rule does not hide slop-001
+// expect: slop-001
+// Get the user name
+const userName = getUserName();
+/* patina-enable slop-003 */
+
+// expect: slop-003
+// Basically, this is outside the disabled range
+run();
+
+// Multi-line commented-out group suppressed from its first line
+// patina-ignore-next-line slop-004
+// const a = compute();
+// const b = a + 1;
+// save(b);
+
+// expect: unused-suppression
+// patina-ignore-next-line slop-005
+const total = sum(values);
+
+// expect: unused-suppression
+// patina-ignore-next-line
+const count = values.length;
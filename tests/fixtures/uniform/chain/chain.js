@@ -0,0 +1,20 @@
+export function first(items) {
+  const kept = items.filter((item) => item.active);
+  const names = kept.map((item) => item.name);
+  return names.join(", ");
+}
+
+export function second(items) {
+  const kept = items.filter((item) => item.active);
+  const names = kept.map((item) => item.name);
+  const sorted = names.sort();
+  return sorted.join(", ");
+}
+
+export function third(items) {
+  const kept = items.filter((item) => item.active);
+  const names = kept.map((item) => item.name);
+  const sorted = names.sort();
+  const unique = [...new Set(sorted)];
+  return unique.join(", ");
+}
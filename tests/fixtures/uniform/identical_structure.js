@@ -0,0 +1,46 @@
+// expect: uniform-001
+export async function fetchUsers(page) {
+  const response = await fetch(`/api/users?page=${page}`);
+  if (!response.ok) {
+    throw new Error(`Failed to fetch users: ${response.status}`);
+  }
+  const data = await response.json();
+  return data.items.map((item) => ({ ...item, kind: "user" }));
+}
+
+export async function fetchOrders(page) {
+  const response = await fetch(`/api/orders?page=${page}`);
+  if (!response.ok) {
+    throw new Error(`Failed to fetch orders: ${response.status}`);
+  }
+  const data = await response.json();
+  return data.items.map((item) => ({ ...item, kind: "order" }));
+}
+
+export async function fetchInvoices(page) {
+  const response = await fetch(`/api/invoices?page=${page}`);
+  if (!response.ok) {
+    throw new Error(`Failed to fetch invoices: ${response.status}`);
+  }
+  const data = await response.json();
+  return data.items.map((item) => ({ ...item, kind: "invoice" }));
+}
+
+export function summarize(orders) {
+  let total = 0;
+  for (const order of orders) {
+    if (order.status === "cancelled") {
+      continue;
+    }
+    total += order.lines.reduce((sum, line) => sum + line.price * line.quantity, 0);
+  }
+  return { count: orders.length, total };
+}
+
+export function isAdmin(user) {
+  return user.roles.includes("admin");
+}
+
+export function isOwner(user) {
+  return user.roles.includes("owner");
+}
@@ -4,3 +4,15 @@
 
 #[path = "integration/scan_test.rs"]
 mod scan_test;
+
+#[path = "integration/config_test.rs"]
+mod config_test;
+
+#[path = "integration/baseline_test.rs"]
+mod baseline_test;
+
+#[path = "integration/fix_test.rs"]
+mod fix_test;
+
+#[path = "integration/hook_test.rs"]
+mod hook_test;
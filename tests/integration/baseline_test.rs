@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn patina_bin() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_patina"))
+}
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("failed to create scratch dir");
+    dir
+}
+
+const ORIGINAL: &str = "\
+// Set the user name
+user.setUserName(name);
+
+// Create the new user account
+createNewUserAccount();
+";
+
+#[test]
+fn baseline_hides_known_findings_and_reports_new_ones() {
+    let dir = scratch_dir("baseline_round_trip");
+    let source = dir.join("app.js");
+    let baseline = dir.join("baseline.json");
+    std::fs::write(&source, ORIGINAL).unwrap();
+
+    let output = patina_bin()
+        .args(["baseline", "create"])
+        .arg(&source)
+        .arg("--output")
+        .arg(&baseline)
+        .output()
+        .expect("failed to run patina");
+    assert!(output.status.success(), "baseline create should succeed");
+
+    let recorded: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&baseline).unwrap()).unwrap();
+    assert_eq!(recorded["findings"].as_array().unwrap().len(), 2);
+
+    // Shift everything down and re-indent: known findings must still match
+    let shifted = format!("\n\n{}", ORIGINAL.replace("user.", "  user."));
+    std::fs::write(&source, &shifted).unwrap();
+    let output = patina_bin()
+        .arg("scan")
+        .arg(&source)
+        .args(["--format", "json", "--baseline"])
+        .arg(&baseline)
+        .output()
+        .expect("failed to run patina");
+    assert!(output.status.success(), "known findings should not fail the scan");
+
+    // A new redundant comment is reported; a removed one is listed as fixed
+    let edited = shifted.replace(
+        "// Create the new user account\ncreateNewUserAccount();\n",
+        "// Get the user name\nconst userName = getUserName();\n",
+    );
+    std::fs::write(&source, edited).unwrap();
+    let output = patina_bin()
+        .arg("scan")
+        .arg(&source)
+        .args(["--format", "json", "--baseline"])
+        .arg(&baseline)
+        .output()
+        .expect("failed to run patina");
+    assert!(!output.status.success(), "new findings should fail the scan");
+
+    let findings: Vec<serde_json::Value> =
+        serde_json::from_str(&String::from_utf8_lossy(&output.stdout)).unwrap();
+    assert_eq!(findings.len(), 1, "only the new finding should be reported");
+    assert_eq!(findings[0]["line"], 6);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("1 known finding(s) hidden, 1 new"), "{stderr}");
+    assert!(stderr.contains("no longer match"), "fixed entries should be listed: {stderr}");
+}
+
+#[test]
+fn missing_baseline_file_is_an_error() {
+    let output = patina_bin()
+        .args([
+            "scan",
+            "tests/fixtures/clean/",
+            "--baseline",
+            "does/not/exist.json",
+        ])
+        .output()
+        .expect("failed to run patina");
+
+    assert_eq!(output.status.code(), Some(2));
+}
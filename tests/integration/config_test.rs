@@ -0,0 +1,195 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::process::Command;
+
+fn patina_bin() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_patina"))
+}
+
+#[test]
+fn config_is_discovered_and_applied() {
+    // No --format flag: JSON comes from `[patina] format` in the fixture's .patina.toml
+    let output = patina_bin()
+        .args(["scan", "tests/fixtures/config/custom/"])
+        .output()
+        .expect("failed to run patina");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let findings: Vec<serde_json::Value> = serde_json::from_str(&stdout)
+        .expect("config format = \"json\" should produce JSON output");
+
+    assert_eq!(findings.len(), 1, "expected 1 finding, got {findings:?}");
+    let finding = &findings[0];
+    assert_eq!(finding["rule_id"], "slop-001");
+    assert_eq!(finding["severity"], "error", "severity override should apply");
+    assert_eq!(finding["line"], 6);
+    assert!(
+        finding["file"].as_str().unwrap().ends_with("app.js"),
+        "vendor/ should be ignored"
+    );
+}
+
+#[test]
+fn config_is_discovered_when_scanning_a_single_file() {
+    let output = patina_bin()
+        .args(["scan", "tests/fixtures/config/custom/vendor/lib.js"])
+        .output()
+        .expect("failed to run patina");
+
+    assert!(output.status.success(), "ignored file should produce no findings");
+}
+
+#[test]
+fn cli_flags_override_config() {
+    let output = patina_bin()
+        .args([
+            "scan",
+            "tests/fixtures/config/custom/",
+            "--format",
+            "json",
+            "--severity-threshold",
+            "error",
+        ])
+        .output()
+        .expect("failed to run patina");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let findings: Vec<serde_json::Value> = serde_json::from_str(&stdout)
+        .expect("output should be valid JSON");
+    assert_eq!(findings.len(), 1, "error-level override should pass an error threshold");
+}
+
+#[test]
+fn invalid_config_reports_unknown_rule_id() {
+    let output = patina_bin()
+        .args(["scan", "tests/fixtures/config/invalid/"])
+        .output()
+        .expect("failed to run patina");
+
+    assert_eq!(output.status.code(), Some(2), "invalid config should exit with 2");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("slop-999"), "error should name the unknown rule: {stderr}");
+    assert!(stderr.contains(".patina.toml"), "error should name the config file: {stderr}");
+}
+
+#[test]
+fn query_rules_from_config_are_applied() {
+    let output = patina_bin()
+        .args(["scan", "tests/fixtures/config/query_rules/", "--format", "json"])
+        .output()
+        .expect("failed to run patina");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let findings: Vec<serde_json::Value> = serde_json::from_str(&stdout)
+        .expect("output should be valid JSON");
+    let found: Vec<(&str, &str, u64, &str)> = findings
+        .iter()
+        .map(|f| {
+            (
+                f["rule_id"].as_str().unwrap(),
+                f["severity"].as_str().unwrap(),
+                f["line"].as_u64().unwrap(),
+                f["message"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    // The alert() on line 14 is suppressed; house-002's severity is overridden
+    assert_eq!(
+        found,
+        vec![
+            ("house-001", "warn", 4, "Browser Dialog: `confirm(`Delete ${item.name}?`)` blocks the page"),
+            ("house-001", "warn", 8, "Browser Dialog: `alert(\"Deleted\")` blocks the page"),
+            ("house-002", "error", 15, "Long Arrow Callback: callback spans more than 5 lines; give it a name"),
+        ]
+    );
+}
+
+#[test]
+fn query_rules_are_listed_and_described_in_sarif() {
+    let output = patina_bin()
+        .current_dir("tests/fixtures/config/query_rules")
+        .args(["rules", "--format", "json"])
+        .output()
+        .expect("failed to run patina");
+    let rules: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).expect("rules should be JSON");
+    let custom: Vec<&str> = rules
+        .iter()
+        .filter_map(|rule| rule["id"].as_str())
+        .filter(|id| id.starts_with("house-"))
+        .collect();
+    assert_eq!(custom, vec!["house-001", "house-002"]);
+
+    let output = patina_bin()
+        .args(["scan", "tests/fixtures/config/query_rules/", "--format", "sarif"])
+        .output()
+        .expect("failed to run patina");
+    let log: serde_json::Value = serde_json::from_slice(&output.stdout).expect("SARIF should be JSON");
+    let run = &log["runs"][0];
+    let index = run["results"][0]["ruleIndex"].as_u64().expect("custom findings should have a ruleIndex");
+    assert_eq!(run["tool"]["driver"]["rules"][index as usize]["id"], "house-001");
+}
+
+#[test]
+fn invalid_query_rule_names_the_rule_file() {
+    let output = patina_bin()
+        .args(["scan", "tests/fixtures/config/bad_query/"])
+        .output()
+        .expect("failed to run patina");
+
+    assert_eq!(output.status.code(), Some(2), "invalid rule file should exit with 2");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("broken.toml"), "error should name the rule file: {stderr}");
+    assert!(stderr.contains("call_expresion"), "error should name the bad node type: {stderr}");
+}
+
+#[test]
+fn plugin_rules_are_listed_and_applied() {
+    let output = patina_bin()
+        .current_dir("tests/fixtures/config/plugins")
+        .args(["rules", "--format", "json"])
+        .output()
+        .expect("failed to run patina");
+    let rules: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).expect("rules should be JSON");
+    let plugin = rules
+        .iter()
+        .find(|rule| rule["id"] == "example-001")
+        .expect("plugin rules should be listed");
+    assert_eq!(plugin["name"], "Example Plugin Rule");
+
+    let output = patina_bin()
+        .args(["scan", "tests/fixtures/config/plugins/", "--format", "json"])
+        .output()
+        .expect("failed to run patina");
+    let findings: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).expect("findings should be JSON");
+    let found: Vec<(&str, u64, u64)> = findings
+        .iter()
+        .filter(|finding| finding["rule_id"] == "example-001")
+        .map(|finding| (finding["message"].as_str().unwrap(), finding["line"].as_u64().unwrap(), finding["column"].as_u64().unwrap()))
+        .collect();
+    assert_eq!(found, vec![("Example Plugin Rule: first word of the file", 1, 1)]);
+}
+
+#[test]
+fn failing_plugin_fails_the_scan() {
+    let output = patina_bin()
+        .args(["scan", "tests/fixtures/config/broken_plugin/"])
+        .output()
+        .expect("failed to run patina");
+
+    assert_eq!(output.status.code(), Some(2), "a broken plugin must not pass silently");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("broken-001") && stderr.contains("app.js"), "error should name the rule and file: {stderr}");
+    assert!(stderr.contains("unreachable"), "error should carry the trap: {stderr}");
+}
+
+#[test]
+fn plugin_fixtures_are_built_from_their_source() {
+    for wat in ["tests/fixtures/config/plugins/plugins/example.wat", "tests/fixtures/config/broken_plugin/broken.wat"] {
+        let wat = std::path::Path::new(wat);
+        let built = wat::parse_file(wat).expect("fixture should compile");
+        let committed = std::fs::read(wat.with_extension("wasm")).expect("compiled fixture should exist");
+        assert!(built == committed, "rebuild {} from its source", wat.display());
+    }
+}
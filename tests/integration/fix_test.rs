@@ -0,0 +1,101 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn patina_bin() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_patina"))
+}
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("failed to create scratch dir");
+    dir
+}
+
+const ORIGINAL: &str = "\
+function save(user) {
+    // Set the user name
+    user.setUserName(name);
+
+    // Let me think about how to persist this
+    db.write(user); // patina-ignore slop-004
+}
+";
+
+const FIXED: &str = "\
+function save(user) {
+    user.setUserName(name);
+
+    db.write(user);
+}
+";
+
+#[test]
+fn fix_dry_run_prints_diff_without_writing() {
+    let dir = scratch_dir("fix_dry_run");
+    let source = dir.join("app.js");
+    std::fs::write(&source, ORIGINAL).unwrap();
+
+    let output = patina_bin()
+        .args(["fix", "--dry-run"])
+        .arg(&source)
+        .output()
+        .expect("failed to run patina");
+
+    assert_eq!(output.status.code(), Some(1), "dry run with pending fixes exits 1");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("@@ -1,7 +1,5 @@"), "{stdout}");
+    assert!(stdout.contains("-    // Set the user name\n"), "{stdout}");
+    assert!(
+        stdout.contains("-    db.write(user); // patina-ignore slop-004\n+    db.write(user);\n"),
+        "{stdout}"
+    );
+    assert_eq!(std::fs::read_to_string(&source).unwrap(), ORIGINAL);
+}
+
+#[test]
+fn fix_rewrites_files_and_leaves_them_clean() {
+    let dir = scratch_dir("fix_apply");
+    let source = dir.join("app.js");
+    std::fs::write(&source, ORIGINAL).unwrap();
+
+    let output = patina_bin()
+        .arg("fix")
+        .arg(&source)
+        .output()
+        .expect("failed to run patina");
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(std::fs::read_to_string(&source).unwrap(), FIXED);
+
+    let rescan = patina_bin()
+        .arg("scan")
+        .arg(&source)
+        .output()
+        .expect("failed to run patina");
+    assert!(rescan.status.success(), "fixed file should have no findings");
+}
+
+#[test]
+fn fix_leaves_unbraced_debug_residue_in_place() {
+    let dir = scratch_dir("fix_unbraced");
+    let source = dir.join("app.js");
+    std::fs::write(&source, "function save(x) {\n  debugger;\n  if (x) debugger;\n  store(x);\n}\n").unwrap();
+
+    let output = patina_bin()
+        .arg("fix")
+        .arg(&source)
+        .output()
+        .expect("failed to run patina");
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    // Deleting the `if` body would make `store(x)` conditional
+    assert_eq!(
+        std::fs::read_to_string(&source).unwrap(),
+        "function save(x) {\n  if (x) debugger;\n  store(x);\n}\n"
+    );
+}
@@ -0,0 +1,105 @@
+// SPDX-FileCopyrightText: 2026 Steven Mosley <astrosteveo>
+//
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn patina_bin() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_patina"))
+}
+
+fn git(repo: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .current_dir(repo)
+        .args(["-c", "user.name=patina", "-c", "user.email=patina@example.com", "-c", "commit.gpgsign=false"])
+        .args(args)
+        .status()
+        .expect("failed to run git");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+/// A fresh repository with one committed JavaScript file.
+fn repo(name: &str) -> PathBuf {
+    let repo = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = std::fs::remove_dir_all(&repo);
+    std::fs::create_dir_all(&repo).unwrap();
+    git(&repo, &["init", "-q"]);
+    std::fs::write(repo.join("app.js"), "export function close(store) {\n  store.close();\n}\n").unwrap();
+    git(&repo, &["add", "app.js"]);
+    git(&repo, &["commit", "-q", "-m", "init"]);
+    repo
+}
+
+#[test]
+fn staged_scan_reads_the_index_not_the_working_tree() {
+    let repo = repo("staged_scan");
+    let staged = "export function close(store) {\n  console.log(\"closing\", store);\n  store.close();\n}\n";
+    std::fs::write(repo.join("app.js"), staged).unwrap();
+    git(&repo, &["add", "app.js"]);
+    // Unstaged edits shift every line down and add residue of their own
+    let working = "\n\nexport function close(store) {\n  console.log(\"closing\", store);\n  console.log(\"unstaged\");\n  store.close();\n}\n";
+    std::fs::write(repo.join("app.js"), working).unwrap();
+
+    let output = patina_bin()
+        .current_dir(&repo)
+        .args(["scan", ".", "--staged", "--format", "json"])
+        .output()
+        .expect("failed to run patina");
+
+    assert_eq!(output.status.code(), Some(1));
+    let findings: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).expect("findings should be JSON");
+    let found: Vec<(&str, u64)> = findings
+        .iter()
+        .map(|f| (f["rule_id"].as_str().unwrap(), f["line"].as_u64().unwrap()))
+        .collect();
+    assert_eq!(found, vec![("slop-007", 2)], "findings should point into the staged content");
+
+    // Debug residue is a warning, so an error threshold lets the commit through
+    std::fs::write(repo.join(".patina.toml"), "[patina]\nseverity = \"error\"\n").unwrap();
+    let output = patina_bin()
+        .current_dir(&repo)
+        .args(["scan", ".", "--staged"])
+        .output()
+        .expect("failed to run patina");
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn installed_hook_blocks_commits_with_findings() {
+    let repo = repo("hook_install");
+    let hook = repo.join(".git/hooks/pre-commit");
+
+    std::fs::write(&hook, "#!/bin/sh\nexit 0\n").unwrap();
+    let output = patina_bin()
+        .current_dir(&repo)
+        .args(["hook", "install"])
+        .output()
+        .expect("failed to run patina");
+    assert_eq!(output.status.code(), Some(2), "a foreign hook is not replaced without --force");
+    assert_eq!(std::fs::read_to_string(&hook).unwrap(), "#!/bin/sh\nexit 0\n");
+
+    for args in [&["hook", "install", "--force"][..], &["hook", "install"][..]] {
+        let output = patina_bin().current_dir(&repo).args(args).output().expect("failed to run patina");
+        assert!(output.status.success(), "{args:?}: {}", String::from_utf8_lossy(&output.stderr));
+    }
+    assert!(std::fs::read_to_string(&hook).unwrap().contains("patina scan . --staged"));
+
+    // Run the hook through a real commit, with the built binary on PATH
+    let bin_dir = Path::new(env!("CARGO_BIN_EXE_patina")).parent().unwrap();
+    let path = std::env::join_paths(
+        std::iter::once(bin_dir.to_path_buf()).chain(std::env::split_paths(&std::env::var_os("PATH").unwrap_or_default())),
+    )
+    .unwrap();
+    std::fs::write(repo.join("app.js"), "export function close(store) {\n  debugger;\n  store.close();\n}\n").unwrap();
+    git(&repo, &["add", "app.js"]);
+    let status = Command::new("git")
+        .current_dir(&repo)
+        .env("PATH", path)
+        .args(["-c", "user.name=patina", "-c", "user.email=patina@example.com", "-c", "commit.gpgsign=false"])
+        .args(["commit", "-q", "-m", "add residue"])
+        .output()
+        .expect("failed to run git")
+        .status;
+    assert!(!status.success(), "the hook should block a commit with findings");
+}
@@ -21,12 +21,12 @@ fn slop_001_detects_redundant_comments() {
     let stdout = String::from_utf8_lossy(&output.stdout);
     let findings: Vec<serde_json::Value> = serde_json::from_str(&stdout)
         .expect("output should be valid JSON");
+    // The fixture's JSDoc block is fair game for the JSDoc rules
+    let findings: Vec<&serde_json::Value> = findings.iter().filter(|f| f["rule_id"] == "slop-001").collect();
 
     assert_eq!(findings.len(), 6, "expected 6 findings, got {}", findings.len());
 
-    // All findings should be slop-001
     for finding in &findings {
-        assert_eq!(finding["rule_id"], "slop-001");
         assert_eq!(finding["severity"], "warn");
     }
 
@@ -85,13 +85,24 @@ fn scanning_nonexistent_path_still_runs() {
     assert!(output.status.success());
 }
 
+/// Returns the rule ID of a `// expect: <rule-id>` or `# expect: <rule-id>` line.
+/// Inside a doc block the line may start with `*`: ` * // expect: <rule-id>`.
+fn expect_tag(line: &str) -> Option<&str> {
+    let trimmed = line.trim();
+    let trimmed = trimmed.strip_prefix('*').map_or(trimmed, str::trim_start);
+    trimmed
+        .strip_prefix("//")
+        .or_else(|| trimmed.strip_prefix('#'))?
+        .trim()
+        .strip_prefix("expect: ")
+}
+
 fn check_expect_annotations(fixture_path: &str) {
     let source = std::fs::read_to_string(fixture_path).expect("fixture should exist");
 
     let mut expected_lines: Vec<usize> = Vec::new();
     for (i, line) in source.lines().enumerate() {
-        let trimmed = line.trim();
-        if trimmed.starts_with("// expect: slop-") {
+        if expect_tag(line).is_some_and(|tag| tag.starts_with("slop-")) {
             // The finding should be on the NEXT line (the actual comment)
             expected_lines.push(i + 2); // +1 for 0-index, +1 for next line
         }
@@ -120,11 +131,9 @@ fn check_expect_annotations(fixture_path: &str) {
 fn check_expect_annotations_for_rule(fixture_path: &str, rule_id: &str) {
     let source = std::fs::read_to_string(fixture_path).expect("fixture should exist");
 
-    let expect_tag = format!("// expect: {rule_id}");
     let mut expected_lines: Vec<usize> = Vec::new();
     for (i, line) in source.lines().enumerate() {
-        let trimmed = line.trim();
-        if trimmed.starts_with(&expect_tag) {
+        if expect_tag(line).is_some_and(|tag| tag.starts_with(rule_id)) {
             expected_lines.push(i + 2);
         }
     }
@@ -153,7 +162,7 @@ fn check_expect_annotations_for_rule(fixture_path: &str, rule_id: &str) {
 
 #[test]
 fn expect_annotations_match_findings_redundant_comments() {
-    check_expect_annotations("tests/fixtures/slop/redundant_comments.js");
+    check_expect_annotations_for_rule("tests/fixtures/slop/redundant_comments.js", "slop-001");
 }
 
 #[test]
@@ -177,7 +186,253 @@ fn expect_annotations_match_findings_self_narrating() {
 }
 
 #[test]
-fn scanning_directory_finds_all_js_files() {
+fn expect_annotations_match_findings_section_banners() {
+    check_expect_annotations_for_rule("tests/fixtures/slop/section_banners.js", "slop-006");
+    check_expect_annotations_for_rule("tests/fixtures/python/section_banners.py", "slop-006");
+}
+
+#[test]
+fn expect_annotations_match_findings_debug_residue() {
+    check_expect_annotations_for_rule("tests/fixtures/debug_residue/app.js", "slop-007");
+}
+
+#[test]
+fn expect_annotations_match_findings_over_documentation() {
+    check_expect_annotations_for_rule("tests/fixtures/slop/over_documentation.js", "slop-008");
+}
+
+#[test]
+fn expect_annotations_match_findings_jsdoc_drift() {
+    check_expect_annotations_for_rule("tests/fixtures/slop/jsdoc_drift.ts", "slop-009");
+}
+
+#[test]
+fn expect_annotations_match_findings_identical_structure() {
+    check_expect_annotations_for_rule("tests/fixtures/uniform/identical_structure.js", "uniform-001");
+}
+
+#[test]
+fn identical_structure_reports_every_member_as_related() {
+    let fixture = "tests/fixtures/uniform/identical_structure.js";
+    let output = patina_bin()
+        .args(["scan", fixture, "--format", "json"])
+        .output()
+        .expect("failed to run patina");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let findings: Vec<serde_json::Value> = serde_json::from_str(&stdout)
+        .expect("output should be valid JSON");
+    let group = findings
+        .iter()
+        .find(|f| f["rule_id"] == "uniform-001")
+        .expect("should report the fetch functions");
+    let related: Vec<u64> = group["related"]
+        .as_array()
+        .expect("should carry related locations")
+        .iter()
+        .map(|r| r["line"].as_u64().unwrap())
+        .collect();
+    assert_eq!(related, vec![2, 11, 20]);
+
+    let output = patina_bin()
+        .args(["scan", fixture, "--format", "sarif"])
+        .output()
+        .expect("failed to run patina");
+    let log: serde_json::Value = serde_json::from_slice(&output.stdout).expect("SARIF should be JSON");
+    let result = &log["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "uniform-001");
+    let locations = result["relatedLocations"].as_array().expect("SARIF relatedLocations");
+    assert_eq!(locations.len(), 3);
+    assert_eq!(locations[1]["physicalLocation"]["region"]["startLine"], 11);
+}
+
+#[test]
+fn identical_structure_reports_the_least_similar_pair_of_a_chained_group() {
+    // `first` and `third` are only grouped through `second`, and are less
+    // alike than the configured threshold of 75%
+    let output = patina_bin()
+        .args(["scan", "tests/fixtures/uniform/chain/chain.js", "--format", "json"])
+        .output()
+        .expect("failed to run patina");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let findings: Vec<serde_json::Value> = serde_json::from_str(&stdout)
+        .expect("output should be valid JSON");
+    let group = findings
+        .iter()
+        .find(|f| f["rule_id"] == "uniform-001")
+        .expect("should group the three functions");
+    let message = group["message"].as_str().unwrap();
+    assert!(message.contains("3 functions"), "{message}");
+    assert!(message.contains("(at least 73% similar)"), "{message}");
+}
+
+#[test]
+fn duplicate_code_is_reported_in_the_later_file_with_every_copy() {
+    let output = patina_bin()
+        .args(["scan", "tests/fixtures/duplicates/", "--format", "json"])
+        .output()
+        .expect("failed to run patina");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let findings: Vec<serde_json::Value> = serde_json::from_str(&stdout)
+        .expect("output should be valid JSON");
+    let duplicates: Vec<(&str, Vec<&str>)> = findings
+        .iter()
+        .filter(|f| f["rule_id"] == "bloat-003")
+        .map(|f| {
+            let related = f["related"]
+                .as_array()
+                .expect("should carry related locations")
+                .iter()
+                .map(|r| r["file"].as_str().unwrap())
+                .collect();
+            (f["file"].as_str().unwrap(), related)
+        })
+        .collect();
+
+    // vendors.js suppresses its copy, and the directive counts as used
+    assert_eq!(
+        duplicates,
+        vec![
+            ("tests/fixtures/duplicates/orders.js", vec!["tests/fixtures/duplicates/billing/invoices.js"]),
+            (
+                "tests/fixtures/duplicates/users.js",
+                vec!["tests/fixtures/duplicates/billing/invoices.js", "tests/fixtures/duplicates/orders.js"]
+            ),
+        ]
+    );
+    assert!(!findings.iter().any(|f| f["file"] == "tests/fixtures/duplicates/vendors.js"));
+}
+
+#[test]
+fn template_naming_reports_the_file_that_departs_from_the_project() {
+    let output = patina_bin()
+        .args(["scan", "tests/fixtures/naming/", "--format", "json"])
+        .output()
+        .expect("failed to run patina");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let findings: Vec<serde_json::Value> = serde_json::from_str(&stdout)
+        .expect("output should be valid JSON");
+    let naming: Vec<&serde_json::Value> = findings.iter().filter(|f| f["rule_id"] == "uniform-002").collect();
+    // events.js names only constants, which are out of the rule's scope
+    assert_eq!(naming.len(), 1, "only widgets.js should be reported: {naming:?}");
+    let finding = naming[0];
+    assert_eq!(finding["file"], "tests/fixtures/naming/widgets.js");
+    assert_eq!(finding["line"], 3);
+
+    let message = finding["message"].as_str().unwrap();
+    assert!(message.contains("observed: camelCase 100%, prefix `handle` 67%, suffix `click` 67%"), "{message}");
+    assert!(message.contains("expected: camelCase 100%, prefix `handle` 0%, suffix `click` 0%"), "{message}");
+    let related: Vec<u64> = finding["related"]
+        .as_array()
+        .expect("should carry related locations")
+        .iter()
+        .map(|r| r["line"].as_u64().unwrap())
+        .collect();
+    assert_eq!(related, vec![3, 7, 11, 15, 19, 23]);
+}
+
+#[test]
+fn debug_residue_skips_allowed_paths_and_fixes_whole_statements() {
+    let output = patina_bin()
+        .args(["scan", "tests/fixtures/debug_residue/", "--format", "json"])
+        .output()
+        .expect("failed to run patina");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let findings: Vec<serde_json::Value> = serde_json::from_str(&stdout)
+        .expect("output should be valid JSON");
+    let residue: Vec<&serde_json::Value> =
+        findings.iter().filter(|f| f["rule_id"] == "slop-007").collect();
+
+    assert!(
+        residue.iter().all(|f| f["file"].as_str().unwrap().ends_with("app.js")),
+        "scripts/ is in allowed_paths and Python is not checked: {residue:?}"
+    );
+    let with_fix: Vec<u64> = residue
+        .iter()
+        .filter(|f| f.get("fix").is_some())
+        .map(|f| f["line"].as_u64().unwrap())
+        .collect();
+    // The console.debug inside an arrow body is not a statement of its own, and
+    // deleting an unbraced `if` body would make the `return` conditional
+    assert_eq!(with_fix, vec![7, 9, 13, 19]);
+    assert!(residue.iter().any(|f| f["line"] == 31 && f.get("fix").is_none()));
+    assert!(residue.iter().any(|f| f["line"] == 33 && f.get("fix").is_none()));
+}
+
+#[test]
+fn expect_annotations_match_findings_python() {
+    check_expect_annotations("tests/fixtures/python/comment_slop.py");
+}
+
+#[test]
+fn python_shebang_and_encoding_lines_are_not_commented_out_code() {
+    check_expect_annotations_for_rule("tests/fixtures/python/script.py", "slop-004");
+}
+
+#[test]
+fn expect_annotations_match_findings_rust() {
+    check_expect_annotations("tests/fixtures/rust/comment_slop.rs");
+}
+
+#[test]
+fn expect_annotations_match_findings_empty_error_handler() {
+    check_expect_annotations_for_rule("tests/fixtures/cult/empty_error_handler.js", "cult-001");
+    check_expect_annotations_for_rule("tests/fixtures/cult/empty_error_handler.py", "cult-001");
+}
+
+#[test]
+fn expect_annotations_match_findings_deep_nesting() {
+    check_expect_annotations_for_rule("tests/fixtures/bloat/deep_nesting.js", "bloat-001");
+    check_expect_annotations_for_rule("tests/fixtures/bloat/deep_nesting.py", "bloat-001");
+}
+
+#[test]
+fn expect_annotations_match_findings_god_function() {
+    check_expect_annotations_for_rule("tests/fixtures/bloat/god_function.js", "bloat-002");
+}
+
+#[test]
+fn god_function_reports_metrics_in_json() {
+    let output = patina_bin()
+        .args(["scan", "tests/fixtures/bloat/god_function.js", "--format", "json"])
+        .output()
+        .expect("failed to run patina");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let findings: Vec<serde_json::Value> = serde_json::from_str(&stdout)
+        .expect("output should be valid JSON");
+    let god_functions: Vec<&serde_json::Value> =
+        findings.iter().filter(|f| f["rule_id"] == "bloat-002").collect();
+
+    let create_user = god_functions[0];
+    assert!(
+        create_user["message"].as_str().unwrap().contains("`createUser`"),
+        "message should name the function: {create_user}"
+    );
+    assert_eq!(
+        create_user["metrics"],
+        serde_json::json!({ "lines": 3, "statements": 1, "complexity": 1, "parameters": 6 })
+    );
+
+    let validate_order = god_functions[1];
+    assert!(
+        validate_order["message"].as_str().unwrap().contains("`validateOrder`"),
+        "message should name the variable an arrow function is assigned to: {validate_order}"
+    );
+    assert!(validate_order["metrics"]["complexity"].as_u64().unwrap() > 15);
+
+    assert!(
+        findings.iter().filter(|f| f["rule_id"] != "bloat-002").all(|f| f.get("metrics").is_none()),
+        "only function-level findings carry metrics"
+    );
+}
+
+#[test]
+fn scanning_directory_finds_all_supported_files() {
     let output = patina_bin()
         .args(["scan", "tests/fixtures/", "--format", "json"])
         .output()
@@ -190,14 +445,18 @@ fn scanning_directory_finds_all_js_files() {
     // Should find the redundant comments from the slop fixture
     assert!(!findings.is_empty(), "should find issues in the fixtures directory");
 
-    // All files in findings should be .js files
+    // All files in findings should be supported source files
     for finding in &findings {
         let file = finding["file"].as_str().unwrap();
         assert!(
-            Path::new(file).extension().is_some_and(|e| e == "js"),
-            "finding should be from a .js file: {file}"
+            Path::new(file).extension().is_some_and(|e| e == "js" || e == "ts" || e == "py" || e == "rs"),
+            "finding should be from a .js, .ts, .py or .rs file: {file}"
         );
     }
+    assert!(
+        findings.iter().any(|f| f["file"].as_str().unwrap().ends_with(".py")),
+        "Python fixtures should be scanned"
+    );
 }
 
 #[test]
@@ -215,6 +474,16 @@ fn rules_subcommand_terminal_output() {
     assert!(stdout.contains("slop-003"), "should list slop-003");
     assert!(stdout.contains("slop-004"), "should list slop-004");
     assert!(stdout.contains("slop-005"), "should list slop-005");
+    assert!(stdout.contains("slop-006"), "should list slop-006");
+    assert!(stdout.contains("slop-007"), "should list slop-007");
+    assert!(stdout.contains("slop-008"), "should list slop-008");
+    assert!(stdout.contains("slop-009"), "should list slop-009");
+    assert!(stdout.contains("cult-001"), "should list cult-001");
+    assert!(stdout.contains("bloat-001"), "should list bloat-001");
+    assert!(stdout.contains("bloat-002"), "should list bloat-002");
+    assert!(stdout.contains("bloat-003"), "should list bloat-003");
+    assert!(stdout.contains("uniform-001"), "should list uniform-001");
+    assert!(stdout.contains("uniform-002"), "should list uniform-002");
     assert!(stdout.contains("Redundant Comment"), "should show rule names");
 }
 
@@ -231,18 +500,26 @@ fn rules_subcommand_json_output() {
     let rules: Vec<serde_json::Value> = serde_json::from_str(&stdout)
         .expect("JSON output should be parseable");
 
-    assert_eq!(rules.len(), 5, "should have 5 rules");
+    assert_eq!(rules.len(), 15, "should have 15 rules");
 
     // Check first rule structure
     let first = &rules[0];
     assert!(first["id"].is_string());
     assert!(first["name"].is_string());
     assert!(first["severity"].is_string());
+    assert!(first["category"].is_string());
     assert!(first["description"].is_string());
 
     // Verify all rule IDs are present
     let ids: Vec<&str> = rules.iter().map(|r| r["id"].as_str().unwrap()).collect();
-    assert_eq!(ids, vec!["slop-001", "slop-002", "slop-003", "slop-004", "slop-005"]);
+    assert_eq!(
+        ids,
+        vec![
+            "slop-001", "slop-002", "slop-003", "slop-004", "slop-005", "slop-006", "slop-007",
+            "slop-008", "slop-009", "cult-001", "bloat-001", "bloat-002", "bloat-003",
+            "uniform-001", "uniform-002",
+        ]
+    );
 }
 
 #[test]
@@ -296,5 +573,226 @@ fn severity_threshold_warn_keeps_warnings() {
     let stdout = String::from_utf8_lossy(&output.stdout);
     let findings: Vec<serde_json::Value> = serde_json::from_str(&stdout)
         .expect("output should be valid JSON");
-    assert_eq!(findings.len(), 6, "warn threshold should keep all warn findings");
+    let redundant = findings.iter().filter(|f| f["rule_id"] == "slop-001").count();
+    assert_eq!(redundant, 6, "warn threshold should keep all warn findings");
+}
+
+#[test]
+fn suppression_comments_hide_matching_findings() {
+    let fixture = "tests/fixtures/suppression/suppressions.js";
+    check_expect_annotations_for_rule(fixture, "slop-001");
+    check_expect_annotations_for_rule(fixture, "slop-003");
+    check_expect_annotations_for_rule(fixture, "slop-004");
+    check_expect_annotations_for_rule(fixture, "slop-005");
+}
+
+#[test]
+fn unused_suppressions_are_reported() {
+    check_expect_annotations_for_rule(
+        "tests/fixtures/suppression/suppressions.js",
+        "unused-suppression",
+    );
+}
+
+#[test]
+fn file_level_disable_suppresses_whole_file() {
+    let output = patina_bin()
+        .args(["scan", "tests/fixtures/suppression/disable_file.js", "--format", "json"])
+        .output()
+        .expect("failed to run patina");
+
+    assert!(output.status.success(), "patina-disable-file should hide every slop-001 finding");
+}
+
+#[test]
+fn sarif_output_is_valid_sarif_log() {
+    let output = patina_bin()
+        .args(["scan", "tests/fixtures/slop/redundant_comments.js", "--format", "sarif"])
+        .output()
+        .expect("failed to run patina");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let log: serde_json::Value = serde_json::from_str(&stdout)
+        .expect("SARIF output should be parseable");
+
+    assert_eq!(log["version"], "2.1.0");
+    let run = &log["runs"][0];
+
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    let ids: Vec<&str> = rules.iter().map(|r| r["id"].as_str().unwrap()).collect();
+    assert!(ids.contains(&"slop-001"), "driver rules should list slop-001");
+    assert_eq!(rules[0]["defaultConfiguration"]["level"], "warning");
+
+    let results: Vec<&serde_json::Value> =
+        run["results"].as_array().unwrap().iter().filter(|r| r["ruleId"] == "slop-001").collect();
+    assert_eq!(results.len(), 6, "expected 6 results, got {}", results.len());
+
+    let first = &results[0];
+    assert_eq!(first["ruleId"], "slop-001");
+    assert_eq!(rules[first["ruleIndex"].as_u64().unwrap() as usize]["id"], "slop-001");
+    assert_eq!(first["level"], "warning");
+
+    let location = &first["locations"][0]["physicalLocation"];
+    assert_eq!(
+        location["artifactLocation"]["uri"],
+        "tests/fixtures/slop/redundant_comments.js"
+    );
+    assert_eq!(location["region"]["startLine"], 6);
+    assert_eq!(location["region"]["startColumn"], 1);
+    assert!(first["partialFingerprints"]["patinaFingerprint/v1"].is_string());
+    assert!(first["fixes"][0]["artifactChanges"][0]["replacements"][0]["deletedRegion"].is_object());
+}
+
+#[test]
+fn parallel_scan_output_is_deterministic() {
+    let run = |jobs: &str| {
+        patina_bin()
+            .args(["scan", "tests/fixtures/", "--format", "json", "--jobs", jobs])
+            .output()
+            .expect("failed to run patina")
+            .stdout
+    };
+
+    let sequential = run("1");
+    assert!(!sequential.is_empty());
+    for _ in 0..3 {
+        assert_eq!(run("4"), sequential, "parallel output should match sequential output byte-for-byte");
+    }
+}
+
+#[test]
+fn rules_explain_prints_long_form_docs() {
+    let output = patina_bin()
+        .args(["rules", "--explain", "slop-004"])
+        .output()
+        .expect("failed to run patina");
+
+    assert!(output.status.success(), "rules --explain should succeed");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Commented-Out Code"), "should show the rule name");
+    assert!(stdout.contains("Why it matters"), "should include the rationale");
+    assert!(stdout.contains("Not flagged"), "should include examples");
+    assert!(stdout.contains("code_line_threshold"), "should document options");
+    assert!(stdout.contains("0.6"), "should show option defaults");
+    assert!(stdout.contains("Known false positives"), "should list false positives");
+}
+
+#[test]
+fn rules_explain_unknown_rule_fails() {
+    let output = patina_bin()
+        .args(["rules", "--explain", "slop-999"])
+        .output()
+        .expect("failed to run patina");
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("slop-999"), "{stderr}");
+}
+
+#[test]
+fn rules_json_output_includes_docs() {
+    let output = patina_bin()
+        .args(["rules", "--format", "json"])
+        .output()
+        .expect("failed to run patina");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let rules: Vec<serde_json::Value> = serde_json::from_str(&stdout)
+        .expect("JSON output should be parseable");
+
+    let slop_001 = rules.iter().find(|r| r["id"] == "slop-001").unwrap();
+    let docs = &slop_001["docs"];
+    assert!(docs["rationale"].is_string());
+    assert!(docs["flagged_example"].is_string());
+    assert_eq!(docs["options"][0]["name"], "threshold");
+    assert_eq!(docs["options"][0]["default"], "0.7");
+}
+
+fn diff_findings(output: &std::process::Output) -> Vec<(String, String, u64)> {
+    let findings: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).expect("findings should be JSON");
+    findings
+        .iter()
+        .map(|f| {
+            (
+                f["rule_id"].as_str().unwrap().to_string(),
+                f["file"].as_str().unwrap().to_string(),
+                f["line"].as_u64().unwrap(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn diff_scan_reports_only_findings_on_changed_lines() {
+    let output = patina_bin()
+        .args([
+            "scan",
+            "tests/fixtures/diff/",
+            "--format",
+            "json",
+            "--diff-file",
+            "tests/fixtures/diff/change.diff",
+        ])
+        .output()
+        .expect("failed to run patina");
+
+    // The commented-out block only gained its last line, but is still reported;
+    // the unchanged console.log and untouched.js are not
+    assert_eq!(
+        diff_findings(&output),
+        vec![
+            ("slop-004".to_string(), "tests/fixtures/diff/app.js".to_string(), 5),
+            ("slop-007".to_string(), "tests/fixtures/diff/app.js".to_string(), 14),
+        ]
+    );
+}
+
+#[test]
+fn diff_scan_reads_the_diff_from_stdin() {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let mut child = patina_bin()
+        .args(["scan", "tests/fixtures/diff/", "--format", "json", "--diff-file", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run patina");
+    let diff = std::fs::read("tests/fixtures/diff/change.diff").unwrap();
+    child.stdin.take().unwrap().write_all(&diff).unwrap();
+    let output = child.wait_with_output().expect("failed to run patina");
+
+    assert_eq!(diff_findings(&output).len(), 2);
+    assert_eq!(output.status.code(), Some(1));
+
+    let output = patina_bin()
+        .args(["scan", "tests/fixtures/diff/", "--format", "json", "--diff-file", "-"])
+        .stdin(Stdio::null())
+        .output()
+        .expect("failed to run patina");
+    assert!(diff_findings(&output).is_empty(), "an empty diff changes nothing");
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn diff_scan_compares_changed_code_with_unchanged_files() {
+    let output = patina_bin()
+        .args([
+            "scan",
+            "tests/fixtures/duplicates/",
+            "--format",
+            "json",
+            "--diff-file",
+            "tests/fixtures/duplicates/change.diff",
+        ])
+        .output()
+        .expect("failed to run patina");
+
+    // users.js duplicates code in files the diff does not touch; orders.js
+    // has the same finding but is unchanged
+    assert_eq!(
+        diff_findings(&output),
+        vec![("bloat-003".to_string(), "tests/fixtures/duplicates/users.js".to_string(), 3)]
+    );
 }